@@ -0,0 +1,183 @@
+//! A validated view of the derive input shared by all derives.
+
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Fields;
+use syn::Ident;
+use syn::Path;
+use syn::Type;
+
+use crate::attr::ContainerAttrs;
+use crate::attr::FieldAttrs;
+use crate::attr::TagType;
+use crate::attr::VariantAttrs;
+
+pub(crate) struct Container<'a> {
+    pub(crate) body: Body<'a>,
+}
+
+pub(crate) enum Body<'a> {
+    Struct(FieldList<'a>),
+    Enum(TagType, Vec<Variant<'a>>),
+}
+
+pub(crate) struct Variant<'a> {
+    pub(crate) ident: &'a Ident,
+    pub(crate) fields: FieldList<'a>,
+    pub(crate) tag: u64,
+}
+
+/// The fields of a struct or a variant, with their parsed attributes.
+pub(crate) struct FieldList<'a> {
+    pub(crate) style: &'a Fields,
+    pub(crate) fields: Vec<Field<'a>>,
+}
+
+pub(crate) struct Field<'a> {
+    /// The field name, or its index for a tuple field, as shown in a decode error path.
+    pub(crate) name: String,
+    pub(crate) ty: &'a Type,
+    pub(crate) kind: FieldKind,
+}
+
+/// How a field is put on the wire.
+pub(crate) enum FieldKind {
+    /// Through its own `Encode`/`Decode` impl.
+    Plain,
+
+    /// Not encoded, decoded as `Default::default()`, or by calling the given function.
+    Skip(Option<Path>),
+
+    /// Through the `encode`/`decode` functions in the given module.
+    With(Path),
+
+    /// Wrapped in `WithChecksum<C, T>`, with the checksum config `C`.
+    Checksum(Type),
+}
+
+impl<'a> Container<'a> {
+    pub(crate) fn from_ast(input: &'a DeriveInput) -> syn::Result<Self> {
+        let attrs = ContainerAttrs::parse(&input.attrs)?;
+
+        let body = match &input.data {
+            Data::Struct(data) => {
+                if let Some((_, lit)) = &attrs.tag_type {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "tag_type is only supported on enums",
+                    ));
+                }
+                Body::Struct(FieldList::parse(&data.fields, &attrs)?)
+            }
+            Data::Enum(data) => {
+                if data.variants.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        &input.ident,
+                        "codeq derive requires an enum with at least one variant",
+                    ));
+                }
+
+                let tag_type = attrs.tag_type.as_ref().map(|(t, _)| *t).unwrap_or_default();
+
+                let mut variants: Vec<Variant> = Vec::new();
+                for v in &data.variants {
+                    let tag = VariantAttrs::parse(&v.attrs)?.tag_value(v, tag_type)?;
+                    if let Some(prev) = variants.iter().find(|x| x.tag == tag) {
+                        return Err(syn::Error::new_spanned(
+                            v,
+                            format!("tag {} is already used by variant `{}`", tag, prev.ident),
+                        ));
+                    }
+
+                    variants.push(Variant {
+                        ident: &v.ident,
+                        fields: FieldList::parse(&v.fields, &attrs)?,
+                        tag,
+                    });
+                }
+                Body::Enum(tag_type, variants)
+            }
+            Data::Union(_) => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "codeq derive does not support unions",
+                ));
+            }
+        };
+
+        Ok(Self { body })
+    }
+
+    /// All fields, of all variants for an enum.
+    pub(crate) fn fields(&self) -> Vec<&Field<'a>> {
+        match &self.body {
+            Body::Struct(list) => list.fields.iter().collect(),
+            Body::Enum(_, variants) => variants.iter().flat_map(|v| &v.fields.fields).collect(),
+        }
+    }
+}
+
+impl<'a> FieldList<'a> {
+    fn parse(style: &'a Fields, container: &ContainerAttrs) -> syn::Result<Self> {
+        let mut fields = Vec::with_capacity(style.len());
+
+        for (i, f) in style.iter().enumerate() {
+            let attrs = FieldAttrs::parse(&f.attrs)?;
+
+            let kind = if attrs.skip {
+                FieldKind::Skip(attrs.default)
+            } else if let Some(module) = attrs.with {
+                FieldKind::With(module)
+            } else if let Some(config) = attrs.checksum {
+                let Some(config) = config.or_else(|| container.config.clone()) else {
+                    return Err(syn::Error::new_spanned(
+                        f,
+                        "#[codeq(checksum)] requires #[codeq(config = \"...\")] on the container, \
+                         or #[codeq(checksum = \"...\")]",
+                    ));
+                };
+                FieldKind::Checksum(config)
+            } else {
+                FieldKind::Plain
+            };
+
+            let name = match &f.ident {
+                Some(ident) => ident.unraw().to_string(),
+                None => i.to_string(),
+            };
+
+            fields.push(Field {
+                name,
+                ty: &f.ty,
+                kind,
+            });
+        }
+
+        Ok(Self { style, fields })
+    }
+
+    /// Local names that fields are bound to when matching a variant or building a value.
+    pub(crate) fn bindings(&self) -> Vec<Ident> {
+        (0..self.fields.len())
+            .map(|i| format_ident!("__f{}", i, span = Span::call_site()))
+            .collect()
+    }
+
+    /// Build `path { a: __f0, b: __f1 }`, `path(__f0, __f1)` or `path`, usable both as a pattern
+    /// and as an expression.
+    pub(crate) fn pattern(&self, path: TokenStream, bindings: &[Ident]) -> TokenStream {
+        match self.style {
+            Fields::Named(named) => {
+                let names = named.named.iter().map(|f| &f.ident);
+                quote! { #path { #(#names: #bindings),* } }
+            }
+            Fields::Unnamed(_) => quote! { #path( #(#bindings),* ) },
+            Fields::Unit => quote! { #path },
+        }
+    }
+}
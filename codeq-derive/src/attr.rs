@@ -0,0 +1,211 @@
+//! Parse `#[codeq(...)]` attributes.
+
+use proc_macro2::Literal;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Attribute;
+use syn::LitInt;
+use syn::LitStr;
+use syn::Path;
+use syn::Token;
+use syn::Type;
+
+/// Width of the tag that precedes every enum variant on the wire.
+#[derive(Clone, Copy, Default)]
+pub(crate) enum TagType {
+    #[default]
+    U8,
+    U16,
+    U32,
+}
+
+impl TagType {
+    fn parse(s: &LitStr) -> syn::Result<Self> {
+        match s.value().as_str() {
+            "u8" => Ok(Self::U8),
+            "u16" => Ok(Self::U16),
+            "u32" => Ok(Self::U32),
+            _ => Err(syn::Error::new_spanned(
+                s,
+                "tag_type must be one of \"u8\", \"u16\" or \"u32\"",
+            )),
+        }
+    }
+
+    /// The primitive integer type of the tag.
+    pub(crate) fn ty(&self) -> TokenStream {
+        match self {
+            Self::U8 => quote!(u8),
+            Self::U16 => quote!(u16),
+            Self::U32 => quote!(u32),
+        }
+    }
+
+    /// A literal of the tag type, such as `3u16`.
+    pub(crate) fn literal(&self, v: u64) -> Literal {
+        match self {
+            Self::U8 => Literal::u8_suffixed(v as u8),
+            Self::U16 => Literal::u16_suffixed(v as u16),
+            Self::U32 => Literal::u32_suffixed(v as u32),
+        }
+    }
+
+    fn max(&self) -> u64 {
+        match self {
+            Self::U8 => u8::MAX as u64,
+            Self::U16 => u16::MAX as u64,
+            Self::U32 => u32::MAX as u64,
+        }
+    }
+}
+
+/// Attributes on the struct or enum itself.
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    /// `#[codeq(tag_type = "u16")]`: the tag width of an enum, `u8` by default.
+    pub(crate) tag_type: Option<(TagType, LitStr)>,
+
+    /// `#[codeq(config = "Crc32fast")]`: the checksum config used by `#[codeq(checksum)]` fields.
+    pub(crate) config: Option<Type>,
+}
+
+impl ContainerAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut res = Self::default();
+
+        for attr in codeq_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tag_type") {
+                    let s: LitStr = meta.value()?.parse()?;
+                    res.tag_type = Some((TagType::parse(&s)?, s));
+                    Ok(())
+                } else if meta.path.is_ident("config") {
+                    let s: LitStr = meta.value()?.parse()?;
+                    res.config = Some(s.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown codeq container attribute"))
+                }
+            })?;
+        }
+
+        Ok(res)
+    }
+}
+
+/// Attributes on an enum variant.
+#[derive(Default)]
+pub(crate) struct VariantAttrs {
+    /// `#[codeq(tag = N)]`: the wire tag of this variant.
+    pub(crate) tag: Option<LitInt>,
+}
+
+impl VariantAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut res = Self::default();
+
+        for attr in codeq_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tag") {
+                    res.tag = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown codeq variant attribute"))
+                }
+            })?;
+        }
+
+        Ok(res)
+    }
+
+    /// Returns the tag value, checking that it is present and fits in `tag_type`.
+    pub(crate) fn tag_value(&self, variant: &syn::Variant, tag_type: TagType) -> syn::Result<u64> {
+        let Some(lit) = &self.tag else {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "enum variant requires an explicit #[codeq(tag = N)]",
+            ));
+        };
+
+        let v: u64 = lit.base10_parse()?;
+        if v > tag_type.max() {
+            return Err(syn::Error::new_spanned(
+                lit,
+                format!("tag {} does not fit in the tag type", v),
+            ));
+        }
+        Ok(v)
+    }
+}
+
+/// Attributes on a field.
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    /// `#[codeq(skip)]`: not encoded, filled with `Default::default()` when decoding.
+    pub(crate) skip: bool,
+
+    /// `#[codeq(skip, default = "path")]`: a skipped field is filled with `path()` instead.
+    pub(crate) default: Option<Path>,
+
+    /// `#[codeq(with = "module")]`: encoded with `module::encode(&field, w)` and decoded with
+    /// `module::decode(r)`.
+    pub(crate) with: Option<Path>,
+
+    /// `#[codeq(checksum)]` or `#[codeq(checksum = "Crc32fast")]`: encoded as
+    /// `WithChecksum<C, T>`. Without a value, the container `config` is used.
+    pub(crate) checksum: Option<Option<Type>>,
+}
+
+impl FieldAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut res = Self::default();
+
+        for attr in codeq_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    res.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    let s: LitStr = meta.value()?.parse()?;
+                    res.default = Some(s.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("with") {
+                    let s: LitStr = meta.value()?.parse()?;
+                    res.with = Some(s.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("checksum") {
+                    if meta.input.peek(Token![=]) {
+                        let s: LitStr = meta.value()?.parse()?;
+                        res.checksum = Some(Some(s.parse()?));
+                    } else {
+                        res.checksum = Some(None);
+                    }
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown codeq field attribute"))
+                }
+            })?;
+
+            let n =
+                res.skip as usize + res.with.is_some() as usize + res.checksum.is_some() as usize;
+            if n > 1 {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "skip, with and checksum can not be combined",
+                ));
+            }
+        }
+
+        if let Some(default) = &res.default {
+            if !res.skip {
+                return Err(syn::Error::new_spanned(default, "default requires skip"));
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+fn codeq_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|a| a.path().is_ident("codeq"))
+}
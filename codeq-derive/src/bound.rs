@@ -0,0 +1,43 @@
+//! Build the where-clause of a derived impl.
+
+use proc_macro2::TokenStream;
+use proc_macro2::TokenTree;
+use quote::ToTokens;
+use syn::Generics;
+use syn::Ident;
+use syn::Type;
+
+/// Returns a copy of `generics` with a `Ty: Bound` predicate for every given field type that
+/// refers to a generic type parameter.
+///
+/// Field types that do not mention any type parameter are concrete, and the compiler checks them
+/// without help; bounding them would only leak private types into the public where-clause.
+pub(crate) fn add_bounds<'a>(
+    generics: &Generics,
+    bounds: impl IntoIterator<Item = (&'a Type, TokenStream)>,
+) -> Generics {
+    let params = generics.type_params().map(|p| p.ident.clone()).collect::<Vec<_>>();
+
+    let mut generics = generics.clone();
+    if params.is_empty() {
+        return generics;
+    }
+
+    let where_clause = generics.make_where_clause();
+    for (ty, bound) in bounds {
+        if mentions_any(ty.to_token_stream(), &params) {
+            where_clause.predicates.push(syn::parse_quote!(#ty: #bound));
+        }
+    }
+
+    generics
+}
+
+/// Returns `true` if the token stream contains any of the given identifiers.
+fn mentions_any(tokens: TokenStream, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => idents.contains(&ident),
+        TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        _ => false,
+    })
+}
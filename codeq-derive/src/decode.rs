@@ -0,0 +1,130 @@
+//! Expand `#[derive(Decode)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+use syn::Ident;
+
+use crate::ast::Body;
+use crate::ast::Container;
+use crate::ast::Field;
+use crate::ast::FieldKind;
+use crate::ast::FieldList;
+use crate::bound::add_bounds;
+use crate::codeq;
+
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let codeq = codeq();
+    let cont = Container::from_ast(input)?;
+
+    let bounds = cont.fields().into_iter().filter_map(|f| match f.kind {
+        FieldKind::Plain | FieldKind::Checksum(_) => Some((f.ty, quote!(#codeq::Decode))),
+        FieldKind::Skip(None) => Some((f.ty, quote!(::std::default::Default))),
+        FieldKind::Skip(Some(_)) | FieldKind::With(_) => None,
+    });
+    let generics = add_bounds(&input.generics, bounds);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &cont.body {
+        Body::Struct(fields) => decode_fields(quote!(Self), fields, None),
+        Body::Enum(tag_type, variants) => {
+            let type_name = input.ident.to_string();
+            let ty = tag_type.ty();
+            let arms = variants.iter().map(|v| {
+                let ident = v.ident;
+                let tag = tag_type.literal(v.tag);
+                let construct = decode_fields(quote!(Self::#ident), &v.fields, Some(ident));
+                quote! {
+                    #tag => { #construct }
+                }
+            });
+            quote! {
+                let __tag = <#ty as #codeq::Decode>::decode_with_order(__order, &mut __r)?;
+
+                match __tag {
+                    #(#arms)*
+                    _ => ::std::result::Result::Err(::std::convert::From::from(
+                        #codeq::Error::InvalidTag {
+                            type_name: #type_name,
+                            tag: __tag as u64,
+                        },
+                    )),
+                }
+            }
+        }
+    };
+
+    let name = &input.ident;
+
+    Ok(quote! {
+        impl #impl_generics #codeq::Decode for #name #ty_generics #where_clause {
+            fn decode<__R: ::std::io::Read>(
+                __r: __R,
+            ) -> ::std::result::Result<Self, ::std::io::Error> {
+                <Self as #codeq::Decode>::decode_with_order(#codeq::ByteOrder::BigEndian, __r)
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn decode_with_order<__R: ::std::io::Read>(
+                __order: #codeq::ByteOrder,
+                mut __r: __R,
+            ) -> ::std::result::Result<Self, ::std::io::Error> {
+                #body
+            }
+        }
+    })
+}
+
+/// Decode fields in declaration order into local bindings, then build the value at `path`.
+///
+/// A field decode error is annotated with the field name, prefixed with `variant.` for an enum.
+/// Plain fields are decoded in `__order`, like [`encode_fields`](crate::encode) encodes them.
+fn decode_fields(path: TokenStream, fields: &FieldList, variant: Option<&Ident>) -> TokenStream {
+    let binds = fields.bindings();
+    let stmts = fields.fields.iter().zip(&binds).map(|(f, b)| {
+        let segment = match variant {
+            Some(v) => format!("{}.{}", v, f.name),
+            None => f.name.clone(),
+        };
+        decode_field(f, b, &segment)
+    });
+    let construct = fields.pattern(path, &binds);
+
+    quote! {
+        #(#stmts)*
+        ::std::result::Result::Ok(#construct)
+    }
+}
+
+fn decode_field(field: &Field, binding: &Ident, segment: &str) -> TokenStream {
+    let codeq = codeq();
+    let ty = field.ty;
+
+    let decode = match &field.kind {
+        FieldKind::Plain => quote! {
+            <#ty as #codeq::Decode>::decode_with_order(__order, &mut __r)
+        },
+        FieldKind::Skip(None) => {
+            return quote! {
+                let #binding = <#ty as ::std::default::Default>::default();
+            };
+        }
+        FieldKind::Skip(Some(default)) => {
+            return quote! {
+                let #binding: #ty = #default();
+            };
+        }
+        FieldKind::With(module) => quote! {
+            #module::decode(&mut __r)
+        },
+        FieldKind::Checksum(config) => quote! {
+            <#codeq::WithChecksum<#config, #ty> as #codeq::Decode>::decode(&mut __r)
+                .map(#codeq::WithChecksum::into_inner)
+        },
+    };
+
+    quote! {
+        let #binding: #ty =
+            #codeq::error_context_ext::ErrorContextExt::in_path(#decode, #segment)?;
+    }
+}
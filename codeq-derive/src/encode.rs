@@ -0,0 +1,107 @@
+//! Expand `#[derive(Encode)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+use syn::Ident;
+
+use crate::ast::Body;
+use crate::ast::Container;
+use crate::ast::FieldKind;
+use crate::ast::FieldList;
+use crate::bound::add_bounds;
+use crate::codeq;
+
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let codeq = codeq();
+    let cont = Container::from_ast(input)?;
+
+    let bounds = cont.fields().into_iter().filter_map(|f| match f.kind {
+        FieldKind::Plain | FieldKind::Checksum(_) => Some((f.ty, quote!(#codeq::Encode))),
+        FieldKind::Skip(_) | FieldKind::With(_) => None,
+    });
+    let generics = add_bounds(&input.generics, bounds);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &cont.body {
+        Body::Struct(fields) => {
+            let binds = fields.bindings();
+            let pat = fields.pattern(quote!(Self), &binds);
+            let encode_fields = encode_fields(fields, &binds);
+            quote! {
+                let #pat = self;
+                #encode_fields
+            }
+        }
+        Body::Enum(tag_type, variants) => {
+            let arms = variants.iter().map(|v| {
+                let ident = v.ident;
+                let binds = v.fields.bindings();
+                let pat = v.fields.pattern(quote!(Self::#ident), &binds);
+                let tag = tag_type.literal(v.tag);
+                let encode_fields = encode_fields(&v.fields, &binds);
+                quote! {
+                    #pat => {
+                        __n += #codeq::Encode::encode_with_order(&#tag, __order, &mut __w)?;
+                        #encode_fields
+                    }
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    };
+
+    let name = &input.ident;
+
+    Ok(quote! {
+        impl #impl_generics #codeq::Encode for #name #ty_generics #where_clause {
+            fn encode<__W: ::std::io::Write>(
+                &self,
+                __w: __W,
+            ) -> ::std::result::Result<usize, ::std::io::Error> {
+                #codeq::Encode::encode_with_order(self, #codeq::ByteOrder::BigEndian, __w)
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn encode_with_order<__W: ::std::io::Write>(
+                &self,
+                __order: #codeq::ByteOrder,
+                mut __w: __W,
+            ) -> ::std::result::Result<usize, ::std::io::Error> {
+                let mut __n = 0usize;
+                #body
+                ::std::result::Result::Ok(__n)
+            }
+        }
+    })
+}
+
+/// Encode every bound field reference in declaration order, accumulating the size into `__n`.
+///
+/// Plain fields are encoded in `__order`; a `with` module and a checksum field, which uses the
+/// byte order of its config, do not take it.
+fn encode_fields(fields: &FieldList, bindings: &[Ident]) -> TokenStream {
+    let codeq = codeq();
+
+    let stmts = fields.fields.iter().zip(bindings).map(|(f, b)| match &f.kind {
+        FieldKind::Plain => quote! {
+            __n += #codeq::Encode::encode_with_order(#b, __order, &mut __w)?;
+        },
+        FieldKind::Skip(_) => quote! {},
+        FieldKind::With(module) => quote! {
+            __n += #module::encode(#b, &mut __w)?;
+        },
+        FieldKind::Checksum(config) => quote! {
+            __n += #codeq::Encode::encode(
+                &#codeq::WithChecksum::<#config, _>::new(#b),
+                &mut __w,
+            )?;
+        },
+    });
+
+    quote! { #(#stmts)* }
+}
@@ -0,0 +1,58 @@
+//! Expand `#[derive(FixedSize)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::ast::Body;
+use crate::ast::Container;
+use crate::ast::FieldKind;
+use crate::bound::add_bounds;
+use crate::codeq;
+
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let codeq = codeq();
+    let cont = Container::from_ast(input)?;
+
+    let Body::Struct(fields) = &cont.body else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "#[derive(FixedSize)] does not support enums",
+        ));
+    };
+
+    let mut sizes = Vec::new();
+    for f in &fields.fields {
+        let ty = f.ty;
+        match &f.kind {
+            FieldKind::Plain => sizes.push(quote!(<#ty as #codeq::FixedSize>::encoded_size())),
+            FieldKind::Skip(_) => {}
+            FieldKind::With(module) => {
+                return Err(syn::Error::new_spanned(
+                    module,
+                    "#[derive(FixedSize)] does not support #[codeq(with)] fields",
+                ));
+            }
+            FieldKind::Checksum(config) => sizes.push(quote!(
+                <#codeq::WithChecksum<#config, #ty> as #codeq::FixedSize>::encoded_size()
+            )),
+        }
+    }
+
+    let bounds = fields.fields.iter().filter_map(|f| match f.kind {
+        FieldKind::Plain | FieldKind::Checksum(_) => Some((f.ty, quote!(#codeq::FixedSize))),
+        FieldKind::Skip(_) | FieldKind::With(_) => None,
+    });
+    let generics = add_bounds(&input.generics, bounds);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let name = &input.ident;
+
+    Ok(quote! {
+        impl #impl_generics #codeq::FixedSize for #name #ty_generics #where_clause {
+            fn encoded_size() -> usize {
+                0 #( + #sizes )*
+            }
+        }
+    })
+}
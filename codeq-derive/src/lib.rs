@@ -0,0 +1,80 @@
+//! Derive macros for the [`codeq`] `Encode`, `Decode` and `FixedSize` traits.
+//!
+//! Do not depend on this crate directly, enable the `derive` feature of `codeq` instead,
+//! which re-exports these macros next to the traits they implement.
+//!
+//! The generated code encodes fields one after another in declaration order,
+//! exactly like a hand-written implementation that sums up `field.encode(&mut w)?`.
+//!
+//! # Attributes
+//!
+//! Container:
+//! - `#[codeq(tag_type = "u8" | "u16" | "u32")]`: tag width of an enum, `u8` by default.
+//! - `#[codeq(config = "path::To::Config")]`: checksum config for `#[codeq(checksum)]` fields.
+//!
+//! Variant:
+//! - `#[codeq(tag = N)]`: the wire tag of the variant, required on every variant.
+//!
+//! Field:
+//! - `#[codeq(skip)]`: not encoded; filled with `Default::default()` when decoding.
+//! - `#[codeq(skip, default = "path")]`: not encoded; filled with `path()` when decoding.
+//! - `#[codeq(with = "module")]`: encoded by `module::encode(&T, W) -> io::Result<usize>` and
+//!   decoded by `module::decode(R) -> io::Result<T>`.
+//! - `#[codeq(checksum)]` or `#[codeq(checksum = "path::To::Config")]`: encoded as `WithChecksum<C,
+//!   T>`, while the field itself stays a plain `T`. The checksum uses the default
+//!   `ChecksumOptions`, so a keyed config, which needs a key, fails to encode or decode.
+//!
+//! [`codeq`]: https://docs.rs/codeq
+
+mod ast;
+mod attr;
+mod bound;
+mod decode;
+mod encode;
+mod fixed_size;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::parse_macro_input;
+use syn::DeriveInput;
+
+/// Derives `codeq::Encode` for a struct or an enum.
+///
+/// Fields are encoded in declaration order and the returned size is the sum of the encoded
+/// size of every field.
+///
+/// An enum variant is encoded as its big-endian tag followed by its fields. Every variant must
+/// declare its tag with `#[codeq(tag = N)]`; the tag width is `u8` unless the enum is annotated
+/// with `#[codeq(tag_type = "u16")]` or `#[codeq(tag_type = "u32")]`. An enum without variants
+/// is rejected, as it has no value to encode.
+#[proc_macro_derive(Encode, attributes(codeq))]
+pub fn derive_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    encode::expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derives `codeq::Decode` for a struct or an enum.
+///
+/// Fields are decoded in declaration order, mirroring the derived `Encode`.
+/// Decoding an enum tag that matches no variant returns an [`InvalidData`] error.
+///
+/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+#[proc_macro_derive(Decode, attributes(codeq))]
+pub fn derive_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    decode::expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derives `codeq::FixedSize` for a struct whose fields are all `FixedSize`.
+///
+/// The encoded size is the sum of the encoded size of every field.
+#[proc_macro_derive(FixedSize, attributes(codeq))]
+pub fn derive_fixed_size(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    fixed_size::expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// The path of the `codeq` crate in generated code.
+pub(crate) fn codeq() -> proc_macro2::TokenStream {
+    quote!(::codeq)
+}
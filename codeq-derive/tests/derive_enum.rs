@@ -0,0 +1,136 @@
+use std::io;
+
+use codeq::config::CodeqConfig;
+use codeq::config::Crc32fast;
+use codeq::testing::test_codec;
+use codeq::ByteOrder;
+use codeq::Decode;
+use codeq::Encode;
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+enum LogEntry {
+    #[codeq(tag = 0)]
+    Blank,
+
+    #[codeq(tag = 1)]
+    Normal { term: u64, data: Vec<u8> },
+
+    #[codeq(tag = 7)]
+    Membership(u32, bool),
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+#[codeq(tag_type = "u16")]
+enum Wide {
+    #[codeq(tag = 0x0102)]
+    A(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+#[codeq(tag_type = "u32")]
+enum Wider<T> {
+    #[codeq(tag = 1)]
+    None,
+
+    #[codeq(tag = 0x01020304)]
+    Some(T),
+}
+
+fn encode<T: Encode>(v: &T) -> io::Result<Vec<u8>> {
+    let mut b = Vec::new();
+    let n = v.encode(&mut b)?;
+    assert_eq!(n, b.len());
+    Ok(b)
+}
+
+#[test]
+fn test_enum_u8_tag() -> anyhow::Result<()> {
+    let cases = [
+        (LogEntry::Blank, vec![0]),
+        (
+            LogEntry::Normal {
+                term: 2,
+                data: vec![5, 6],
+            },
+            vec![
+                1, // tag
+                0, 0, 0, 0, 0, 0, 0, 2, // term
+                0, 0, 0, 2, 5, 6, // data
+            ],
+        ),
+        (LogEntry::Membership(3, true), vec![7, 0, 0, 0, 3, 1]),
+    ];
+
+    for (v, want) in cases {
+        let b = encode(&v)?;
+        assert_eq!(want, b);
+        assert_eq!(v, LogEntry::decode(&mut b.as_slice())?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_enum_matches_option_tagging() -> anyhow::Result<()> {
+    // A hand-rolled `u8` tag before the payload, like `Option<T>` does.
+    let mut want = Vec::new();
+    7u8.encode(&mut want)?;
+    (3u32, true).encode(&mut want)?;
+
+    assert_eq!(want, encode(&LogEntry::Membership(3, true))?);
+
+    Ok(())
+}
+
+#[test]
+fn test_enum_wide_tags() -> anyhow::Result<()> {
+    let b = encode(&Wide::A(9))?;
+    assert_eq!(vec![1, 2, 9], b);
+    assert_eq!(Wide::A(9), Wide::decode(&mut b.as_slice())?);
+
+    let b = encode(&Wider::Some(9u8))?;
+    assert_eq!(vec![1, 2, 3, 4, 9], b);
+    assert_eq!(Wider::Some(9u8), Wider::decode(&mut b.as_slice())?);
+
+    let b = encode(&Wider::<u8>::None)?;
+    assert_eq!(vec![0, 0, 0, 1], b);
+    assert_eq!(Wider::<u8>::None, Wider::decode(&mut b.as_slice())?);
+
+    Ok(())
+}
+
+#[test]
+fn test_enum_little_endian_tags() -> anyhow::Result<()> {
+    let mut b = Vec::new();
+    Wider::Some(9u16).encode_with_order(ByteOrder::LittleEndian, &mut b)?;
+    assert_eq!(vec![4, 3, 2, 1, 9, 0], b);
+    assert_eq!(
+        Wider::Some(9u16),
+        Wider::decode_with_order(ByteOrder::LittleEndian, &mut b.as_slice())?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_enum_unknown_tag() -> anyhow::Result<()> {
+    let err = LogEntry::decode(&mut [2u8].as_slice()).unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+    assert_eq!("Invalid LogEntry tag: 2", err.to_string());
+
+    let err = Wide::decode(&mut [1u8, 3, 9].as_slice()).unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+    assert_eq!("Invalid Wide tag: 259", err.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn test_enum_with_checksum() -> anyhow::Result<()> {
+    let v = Crc32fast::wrap(LogEntry::Membership(3, true));
+    let b = encode(&v)?;
+
+    test_codec(&b, &v)?;
+
+    Ok(())
+}
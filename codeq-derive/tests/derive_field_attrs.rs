@@ -0,0 +1,205 @@
+use std::io;
+
+use codeq::config::CodeqConfig;
+use codeq::config::Crc32fast;
+use codeq::Decode;
+use codeq::Encode;
+use codeq::FixedSize;
+use codeq::WithChecksum;
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode, FixedSize)]
+struct Skipped {
+    id: u32,
+    #[codeq(skip)]
+    cache: u64,
+}
+
+fn unknown() -> Option<u64> {
+    Some(u64::MAX)
+}
+
+/// A skipped field whose type is not `Default`.
+#[derive(Debug, Clone, PartialEq)]
+struct NoDefault(Option<u64>);
+
+fn no_default() -> NoDefault {
+    NoDefault(None)
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode, FixedSize)]
+struct SkippedWithDefault {
+    id: u32,
+    #[codeq(skip, default = "unknown")]
+    cache: Option<u64>,
+    #[codeq(skip, default = "no_default")]
+    extra: NoDefault,
+}
+
+/// Encode a `u64` as a `u32`, for testing `#[codeq(with)]`.
+mod as_u32 {
+    use std::io;
+
+    use codeq::Decode;
+    use codeq::Encode;
+
+    pub fn encode<W: io::Write>(v: &u64, w: W) -> io::Result<usize> {
+        (*v as u32).encode(w)
+    }
+
+    pub fn decode<R: io::Read>(r: R) -> io::Result<u64> {
+        Ok(u32::decode(r)? as u64)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+struct With {
+    #[codeq(with = "as_u32")]
+    v: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode, FixedSize)]
+#[codeq(config = "Crc32fast")]
+struct Checksummed {
+    #[codeq(checksum)]
+    a: u64,
+
+    #[codeq(checksum = "codeq::config::Crc32fast")]
+    b: u64,
+}
+
+/// The existing on-disk layout that [`Checksummed`] must keep.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+struct Wrapped {
+    a: WithChecksum<Crc32fast, u64>,
+    b: WithChecksum<Crc32fast, u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+enum Entry {
+    #[codeq(tag = 1)]
+    Data {
+        #[codeq(checksum = "Crc32fast")]
+        payload: Vec<u8>,
+        #[codeq(skip)]
+        local: bool,
+    },
+}
+
+#[test]
+fn test_skip() -> anyhow::Result<()> {
+    assert_eq!(4, Skipped::encoded_size());
+
+    let v = Skipped { id: 1, cache: 99 };
+
+    let mut b = Vec::new();
+    let n = v.encode(&mut b)?;
+    assert_eq!(n, b.len());
+    assert_eq!(vec![0, 0, 0, 1], b);
+
+    let got = Skipped::decode(&mut b.as_slice())?;
+    assert_eq!(Skipped { id: 1, cache: 0 }, got);
+
+    Ok(())
+}
+
+#[test]
+fn test_skip_with_default() -> anyhow::Result<()> {
+    assert_eq!(4, SkippedWithDefault::encoded_size());
+
+    let v = SkippedWithDefault {
+        id: 1,
+        cache: None,
+        extra: NoDefault(Some(3)),
+    };
+
+    let mut b = Vec::new();
+    let n = v.encode(&mut b)?;
+    assert_eq!(n, b.len());
+    assert_eq!(vec![0, 0, 0, 1], b);
+
+    let got = SkippedWithDefault::decode(&mut b.as_slice())?;
+    assert_eq!(
+        SkippedWithDefault {
+            id: 1,
+            cache: Some(u64::MAX),
+            extra: NoDefault(None),
+        },
+        got
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_with() -> anyhow::Result<()> {
+    let v = With { v: 5 };
+
+    let mut b = Vec::new();
+    let n = v.encode(&mut b)?;
+    assert_eq!(n, b.len());
+    assert_eq!(vec![0, 0, 0, 5], b);
+
+    assert_eq!(v, With::decode(&mut b.as_slice())?);
+
+    Ok(())
+}
+
+#[test]
+fn test_checksum() -> anyhow::Result<()> {
+    assert_eq!(32, Checksummed::encoded_size());
+
+    let v = Checksummed { a: 5, b: 6 };
+
+    let mut b = Vec::new();
+    let n = v.encode(&mut b)?;
+    assert_eq!(n, b.len());
+
+    let mut want = Vec::new();
+    Wrapped {
+        a: Crc32fast::wrap(5),
+        b: Crc32fast::wrap(6),
+    }
+    .encode(&mut want)?;
+
+    assert_eq!(want, b);
+    assert_eq!(
+        vec![
+            0, 0, 0, 0, 0, 0, 0, 5, // a
+            0, 0, 0, 0, 21, 72, 43, 230, // a checksum
+        ],
+        b[..16].to_vec()
+    );
+
+    assert_eq!(v, Checksummed::decode(&mut b.as_slice())?);
+
+    // Corrupted checksum
+    b[15] ^= 1;
+    let err = Checksummed::decode(&mut b.as_slice()).unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+    Ok(())
+}
+
+#[test]
+fn test_enum_field_attrs() -> anyhow::Result<()> {
+    let v = Entry::Data {
+        payload: vec![1, 2],
+        local: true,
+    };
+
+    let mut b = Vec::new();
+    let n = v.encode(&mut b)?;
+    assert_eq!(n, b.len());
+    assert_eq!(1 + 4 + 2 + 8, b.len());
+
+    let got = Entry::decode(&mut b.as_slice())?;
+    assert_eq!(
+        Entry::Data {
+            payload: vec![1, 2],
+            local: false,
+        },
+        got
+    );
+
+    Ok(())
+}
@@ -0,0 +1,211 @@
+use std::io;
+
+use codeq::config::CodeqConfig;
+use codeq::config::Crc32fast;
+use codeq::testing::test_codec;
+use codeq::Decode;
+use codeq::Encode;
+use codeq::FixedSize;
+use codeq::LocatedError;
+use codeq::OffsetReader;
+use codeq::Segment;
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+struct Record {
+    id: u32,
+    data: Vec<u8>,
+}
+
+/// The hand-written counterpart of [`Record`].
+#[derive(Debug, Clone, PartialEq)]
+struct ManualRecord {
+    id: u32,
+    data: Vec<u8>,
+}
+
+impl codeq::Encode for ManualRecord {
+    fn encode<W: io::Write>(&self, mut w: W) -> io::Result<usize> {
+        let mut n = 0;
+        n += self.id.encode(&mut w)?;
+        n += self.data.encode(&mut w)?;
+        Ok(n)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, FixedSize)]
+struct Pair(u64, u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, FixedSize)]
+struct Empty;
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode, FixedSize)]
+struct Header<C>
+where C: CodeqConfig
+{
+    version: u8,
+    segment: Segment<C>,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+struct Wrapper<T> {
+    inner: Option<T>,
+    flag: bool,
+}
+
+#[test]
+fn test_named_struct() -> anyhow::Result<()> {
+    let r = Record {
+        id: 1,
+        data: vec![1, 2, 3],
+    };
+
+    let mut b = Vec::new();
+    let n = r.encode(&mut b)?;
+    assert_eq!(n, b.len());
+    assert_eq!(
+        vec![
+            0, 0, 0, 1, // id
+            0, 0, 0, 3, 1, 2, 3, // data
+        ],
+        b
+    );
+    assert_eq!(r, Record::decode(&mut b.as_slice())?);
+
+    // Checksum-protected, every corrupted byte must be detected
+    test_codec(
+        &[
+            0, 0, 0, 1, // id
+            0, 0, 0, 3, 1, 2, 3, // data
+            0, 0, 0, 0, 31, 101, 71, 147, // checksum
+        ],
+        &Crc32fast::wrap(r),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_named_struct_matches_hand_written() -> anyhow::Result<()> {
+    let derived = Record {
+        id: 7,
+        data: vec![9, 8],
+    };
+    let manual = ManualRecord {
+        id: 7,
+        data: vec![9, 8],
+    };
+
+    let mut a = Vec::new();
+    let na = derived.encode(&mut a)?;
+
+    let mut b = Vec::new();
+    let nb = manual.encode(&mut b)?;
+
+    assert_eq!(na, nb);
+    assert_eq!(a, b);
+
+    Ok(())
+}
+
+#[test]
+fn test_tuple_struct() -> anyhow::Result<()> {
+    assert_eq!(12, Pair::encoded_size());
+
+    let mut b = Vec::new();
+    let n = Pair(1, 2).encode(&mut b)?;
+    assert_eq!(n, b.len());
+    assert_eq!(
+        vec![
+            0, 0, 0, 0, 0, 0, 0, 1, // 0
+            0, 0, 0, 2, // 1
+        ],
+        b
+    );
+    assert_eq!(Pair(1, 2), Pair::decode(&mut b.as_slice())?);
+
+    Ok(())
+}
+
+#[test]
+fn test_unit_struct() -> anyhow::Result<()> {
+    assert_eq!(0, Empty::encoded_size());
+
+    let mut b = Vec::new();
+    assert_eq!(0, Empty.encode(&mut b)?);
+    assert!(b.is_empty());
+
+    assert_eq!(Empty, Empty::decode(&mut b.as_slice())?);
+
+    Ok(())
+}
+
+#[test]
+fn test_generic_struct() -> anyhow::Result<()> {
+    assert_eq!(1 + 24, Header::<Crc32fast>::encoded_size());
+
+    let h = Header {
+        version: 3,
+        segment: Crc32fast::segment(5, 10),
+    };
+
+    let mut b = Vec::new();
+    let n = h.encode(&mut b)?;
+    assert_eq!(n, b.len());
+    assert_eq!(
+        vec![
+            3, // version
+            0, 0, 0, 0, 0, 0, 0, 5, // offset
+            0, 0, 0, 0, 0, 0, 0, 10, // size
+            0, 0, 0, 0, 70, 249, 231, 4, // checksum
+        ],
+        b
+    );
+    assert_eq!(h, Header::decode(&mut b.as_slice())?);
+
+    Ok(())
+}
+
+#[test]
+fn test_generic_field_bound() -> anyhow::Result<()> {
+    let w = Wrapper {
+        inner: Some(5u8),
+        flag: true,
+    };
+
+    let mut b = Vec::new();
+    w.encode(&mut b)?;
+    assert_eq!(b, vec![1, 5, 1]);
+
+    assert_eq!(w, Wrapper::<u8>::decode(&mut b.as_slice())?);
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_error_location() -> anyhow::Result<()> {
+    let mut b = Vec::new();
+    Record {
+        id: 1,
+        data: vec![1, 2],
+    }
+    .encode(&mut b)?;
+
+    // Truncated in the length prefix of `data`
+    let mut r = OffsetReader::new(&b[..6]);
+    let err = r.decode::<Record>().unwrap_err();
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+
+    let located = LocatedError::from_io(&err).unwrap();
+    assert_eq!("Record.data.len", located.path());
+    assert_eq!(Some(6), located.offset());
+
+    // Invalid tag of a field
+    let b = [2u8, 1];
+    let err = OffsetReader::new(&b[..]).decode::<Wrapper<u8>>().unwrap_err();
+    assert_eq!(
+        "Invalid Option tag: 2; at:(Wrapper<u8>.inner, offset 1)",
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use codeq::Encode;
+
+#[derive(Encode)]
+enum Entry {
+    #[codeq(tag = 1)]
+    Blank,
+
+    #[codeq(tag = 1)]
+    Normal(u64),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use codeq::Encode;
+
+#[derive(Encode)]
+enum Entry {
+    #[codeq(tag = 0)]
+    Blank,
+
+    Normal(u64),
+}
+
+fn main() {}
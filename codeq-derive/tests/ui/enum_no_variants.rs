@@ -0,0 +1,7 @@
+use codeq::Decode;
+use codeq::Encode;
+
+#[derive(Encode, Decode)]
+enum Never {}
+
+fn main() {}
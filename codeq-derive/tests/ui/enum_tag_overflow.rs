@@ -0,0 +1,9 @@
+use codeq::Encode;
+
+#[derive(Encode)]
+enum Entry {
+    #[codeq(tag = 256)]
+    Blank,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use codeq::Encode;
+
+#[derive(Encode)]
+#[codeq(tag_type = "u64")]
+enum Entry {
+    #[codeq(tag = 0)]
+    Blank,
+}
+
+fn main() {}
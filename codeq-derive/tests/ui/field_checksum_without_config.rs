@@ -0,0 +1,9 @@
+use codeq::Encode;
+
+#[derive(Encode)]
+struct Record {
+    #[codeq(checksum)]
+    id: u64,
+}
+
+fn main() {}
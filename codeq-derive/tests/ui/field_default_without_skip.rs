@@ -0,0 +1,13 @@
+use codeq::Decode;
+
+fn unknown() -> u64 {
+    u64::MAX
+}
+
+#[derive(Decode)]
+struct Record {
+    #[codeq(default = "unknown")]
+    id: u64,
+}
+
+fn main() {}
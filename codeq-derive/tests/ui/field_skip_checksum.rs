@@ -0,0 +1,10 @@
+use codeq::Encode;
+
+#[derive(Encode)]
+#[codeq(config = "codeq::config::Crc32fast")]
+struct Record {
+    #[codeq(skip, checksum)]
+    id: u64,
+}
+
+fn main() {}
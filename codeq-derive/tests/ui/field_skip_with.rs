@@ -0,0 +1,17 @@
+use codeq::Encode;
+
+mod as_u32 {
+    use std::io;
+
+    pub fn encode<W: io::Write>(v: &u64, w: W) -> io::Result<usize> {
+        codeq::Encode::encode(&(*v as u32), w)
+    }
+}
+
+#[derive(Encode)]
+struct Record {
+    #[codeq(skip, with = "as_u32")]
+    id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use codeq::Encode;
+
+#[derive(Encode)]
+#[codeq(tag_type = "u16")]
+struct Record {
+    id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,97 @@
+/// The byte order of multi-byte integers, floats, length prefixes and checksums.
+///
+/// [`Encode::encode()`] and [`Decode::decode()`] are big-endian; [`Encode::encode_with_order()`]
+/// and [`Decode::decode_with_order()`] take the byte order as an argument and pass it down to
+/// every nested value. [`WithChecksum`] and [`Segment`] encode their content in
+/// [`CodeqConfig::BYTE_ORDER`], so the byte order is a property of the config.
+///
+/// Example:
+/// ```rust
+/// use codeq::{ByteOrder, Encode};
+///
+/// assert_eq!(1u32.encode_to_vec().unwrap(), vec![0, 0, 0, 1]);
+///
+/// let mut buf = Vec::new();
+/// 1u32.encode_with_order(ByteOrder::LittleEndian, &mut buf).unwrap();
+/// assert_eq!(buf, vec![1, 0, 0, 0]);
+/// ```
+///
+/// [`Encode::encode()`]: crate::Encode::encode
+/// [`Decode::decode()`]: crate::Decode::decode
+/// [`Encode::encode_with_order()`]: crate::Encode::encode_with_order
+/// [`Decode::decode_with_order()`]: crate::Decode::decode_with_order
+/// [`WithChecksum`]: crate::WithChecksum
+/// [`Segment`]: crate::Segment
+/// [`CodeqConfig::BYTE_ORDER`]: crate::config::CodeqConfig::BYTE_ORDER
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ByteOrder {
+    /// Most significant byte first, the default.
+    #[default]
+    BigEndian,
+
+    /// Least significant byte first.
+    LittleEndian,
+}
+
+/// Evaluates `$body` with `$O` aliased to the `byteorder` type of the given [`ByteOrder`].
+macro_rules! with_byte_order {
+    ($order:expr, $O:ident => $body:expr) => {
+        match $order {
+            $crate::ByteOrder::BigEndian => {
+                type $O = byteorder::BigEndian;
+                $body
+            }
+            $crate::ByteOrder::LittleEndian => {
+                type $O = byteorder::LittleEndian;
+                $body
+            }
+        }
+    };
+}
+
+pub(crate) use with_byte_order;
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::ByteOrder;
+    use crate::Decode;
+    use crate::Encode;
+
+    fn encode_le<T: Encode>(v: &T) -> Result<Vec<u8>, io::Error> {
+        let mut buf = Vec::new();
+        v.encode_with_order(ByteOrder::LittleEndian, &mut buf)?;
+        Ok(buf)
+    }
+
+    #[test]
+    fn test_little_endian_primitives() -> Result<(), io::Error> {
+        assert_eq!(vec![2, 1], encode_le(&0x0102u16)?);
+        assert_eq!(vec![4, 3, 2, 1], encode_le(&0x01020304u32)?);
+        assert_eq!(vec![0xfe, 0xff], encode_le(&-2i16)?);
+        assert_eq!(vec![0, 0, 0xc0, 0x3f], encode_le(&1.5f32)?);
+
+        // Length prefix
+        assert_eq!(vec![3, 0, 0, 0, b'f', b'o', b'o'], encode_le(&"foo")?);
+        assert_eq!(vec![2, 0, 0, 0, 1, 0, 2, 0], encode_le(&vec![1u16, 2])?);
+
+        let buf = encode_le(&0x0102030405060708u64)?;
+        assert_eq!(vec![8, 7, 6, 5, 4, 3, 2, 1], buf);
+        assert_eq!(
+            0x0102030405060708u64,
+            u64::decode_with_order(ByteOrder::LittleEndian, &mut buf.as_slice())?
+        );
+
+        let buf = encode_le(&vec![1u32, 2])?;
+        assert_eq!(
+            vec![1u32, 2],
+            Vec::<u32>::decode_with_order(ByteOrder::LittleEndian, &mut buf.as_slice())?
+        );
+
+        // The default is big-endian
+        assert_eq!(vec![1, 2], 0x0102u16.encode_to_vec()?);
+
+        Ok(())
+    }
+}
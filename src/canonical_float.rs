@@ -0,0 +1,165 @@
+use std::io;
+use std::io::Read;
+use std::io::Write;
+
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+/// A floating point number encoded with a single canonical NaN.
+///
+/// `f32`/`f64` encode their exact IEEE-754 bits, so two NaNs that compare the same in
+/// application logic may produce different bytes, and therefore different checksums when wrapped
+/// in [`WithChecksum`]. This wrapper encodes every NaN as the quiet NaN
+/// (`0x7fc00000` for `f32`, `0x7ff8000000000000` for `f64`), and rejects any other NaN when
+/// decoding, so that the same value always has exactly one encoding.
+///
+/// Non-NaN values, including `-0.0` and infinities, are encoded exactly like the bare float.
+///
+/// Example:
+/// ```rust
+/// use codeq::{CanonicalFloat, Encode};
+///
+/// let a = CanonicalFloat(f64::NAN).encode_to_vec().unwrap();
+/// let b = CanonicalFloat(-f64::NAN).encode_to_vec().unwrap();
+/// assert_eq!(a, b);
+/// ```
+///
+/// [`WithChecksum`]: crate::WithChecksum
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct CanonicalFloat<T>(pub T);
+
+impl<T> CanonicalFloat<T> {
+    /// Unwraps and returns the inner float.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for CanonicalFloat<T> {
+    fn from(v: T) -> Self {
+        Self(v)
+    }
+}
+
+macro_rules! impl_canonical_float {
+    ($float:ty, $bits:ty, $canonical_nan:expr) => {
+        impl FixedSize for CanonicalFloat<$float> {
+            fn encoded_size() -> usize {
+                <$float>::encoded_size()
+            }
+        }
+
+        impl Encode for CanonicalFloat<$float> {
+            fn encode<W: Write>(&self, w: W) -> Result<usize, io::Error> {
+                self.encode_with_order(ByteOrder::BigEndian, w)
+            }
+
+            fn encode_with_order<W: Write>(
+                &self,
+                order: ByteOrder,
+                w: W,
+            ) -> Result<usize, io::Error> {
+                let bits = if self.0.is_nan() {
+                    $canonical_nan
+                } else {
+                    self.0.to_bits()
+                };
+                bits.encode_with_order(order, w)
+            }
+        }
+
+        impl Decode for CanonicalFloat<$float> {
+            fn decode<R: Read>(r: R) -> Result<Self, io::Error> {
+                Self::decode_with_order(ByteOrder::BigEndian, r)
+            }
+
+            fn decode_with_order<R: Read>(order: ByteOrder, r: R) -> Result<Self, io::Error> {
+                let bits = <$bits>::decode_with_order(order, r)?;
+                let v = <$float>::from_bits(bits);
+
+                if v.is_nan() && bits != $canonical_nan {
+                    return Err(crate::Error::NonCanonicalNan { bits: bits as u64 }.into());
+                }
+                Ok(Self(v))
+            }
+        }
+    };
+}
+
+impl_canonical_float!(f32, u32, 0x7fc0_0000u32);
+impl_canonical_float!(f64, u64, 0x7ff8_0000_0000_0000u64);
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::CanonicalFloat;
+    use crate::Decode;
+    use crate::Encode;
+    use crate::FixedSize;
+
+    #[test]
+    fn test_canonical_float_non_nan() -> anyhow::Result<()> {
+        assert_eq!(4, CanonicalFloat::<f32>::encoded_size());
+        assert_eq!(8, CanonicalFloat::<f64>::encoded_size());
+
+        for v in [0.0f64, -0.0, 1.5, f64::INFINITY] {
+            let buf = CanonicalFloat(v).encode_to_vec()?;
+            assert_eq!(v.encode_to_vec()?, buf);
+
+            let got = CanonicalFloat::<f64>::decode(&mut buf.as_slice())?;
+            assert_eq!(v.to_bits(), got.0.to_bits());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonical_float_nan() -> anyhow::Result<()> {
+        let nan_f32 = [f32::NAN, -f32::NAN, f32::from_bits(0x7f80_0001)];
+        for v in nan_f32 {
+            let buf = CanonicalFloat(v).encode_to_vec()?;
+            assert_eq!(vec![0x7f, 0xc0, 0, 0], buf);
+            assert!(CanonicalFloat::<f32>::decode(&mut buf.as_slice())?.0.is_nan());
+        }
+
+        let nan_f64 = [f64::NAN, -f64::NAN, f64::from_bits(0x7ff0_0000_0000_0001)];
+        for v in nan_f64 {
+            let buf = CanonicalFloat(v).encode_to_vec()?;
+            assert_eq!(vec![0x7f, 0xf8, 0, 0, 0, 0, 0, 0], buf);
+            assert!(CanonicalFloat::<f64>::decode(&mut buf.as_slice())?.0.is_nan());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonical_float_rejects_other_nan() -> anyhow::Result<()> {
+        let buf = f32::from_bits(0x7fc0_0001).encode_to_vec()?;
+        let err = CanonicalFloat::<f32>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!("Non-canonical NaN: 0x7fc00001", err.to_string());
+
+        let buf = f64::from_bits(0xfff8_0000_0000_0000).encode_to_vec()?;
+        let err = CanonicalFloat::<f64>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "crc32fast")]
+    #[test]
+    fn test_canonical_float_deterministic_checksum() -> anyhow::Result<()> {
+        use crate::config::CodeqConfig;
+        use crate::config::Crc32fast;
+
+        let a = Crc32fast::wrap(CanonicalFloat(f64::NAN)).encode_to_vec()?;
+        let b = Crc32fast::wrap(CanonicalFloat(f64::from_bits(0x7ff0_dead_beef_0001)))
+            .encode_to_vec()?;
+        assert_eq!(a, b);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// A 256-bit secret key of the keyed checksum configs, such as `HmacSha256` and `Blake3Keyed`.
+///
+/// The key is passed explicitly with the [`ChecksumOptions`] of a reader, a writer or the
+/// `encode_with_options()`/`decode_with_options()` of [`WithChecksum`] and [`Segment`], so that a
+/// keyed config can protect data with a key only known at runtime, e.g., one per peer.
+///
+/// It is not `Copy`, so that the key is only duplicated by an explicit `clone()`.
+///
+/// [`ChecksumOptions`]: crate::ChecksumOptions
+/// [`WithChecksum`]: crate::WithChecksum
+/// [`Segment`]: crate::Segment
+#[derive(Clone)]
+pub struct ChecksumKey([u8; 32]);
+
+impl fmt::Debug for ChecksumKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ChecksumKey(..)")
+    }
+}
+
+impl ChecksumKey {
+    /// Creates a key from its bytes.
+    pub const fn new(key: [u8; 32]) -> Self {
+        Self(key)
+    }
+
+    /// Returns the bytes of the key.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChecksumKey;
+
+    #[test]
+    fn test_debug_hides_key() {
+        assert_eq!(
+            "ChecksumKey(..)",
+            format!("{:?}", ChecksumKey::new([1; 32]))
+        );
+    }
+}
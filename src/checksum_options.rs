@@ -0,0 +1,49 @@
+use crate::ChecksumKey;
+
+/// Runtime inputs of a checksum that are not part of its config, such as the key of a keyed
+/// checksum or the salt of a [`Seeded`] one.
+///
+/// Pass them to [`ChecksumWriter::with_options()`], [`ChecksumReader::with_options()`], or
+/// `encode_with_options()`/`decode_with_options()` of [`WithChecksum`] and [`Segment`]. The plain
+/// codecs use the default, empty options; the plain constructors, such as
+/// [`ChecksumWriter::new()`], take none and only accept a checksum that needs no key.
+///
+/// Example:
+#[cfg_attr(not(feature = "hmac"), doc = "```ignore")]
+#[cfg_attr(feature = "hmac", doc = "```rust")]
+/// use codeq::config::{CodeqConfig, HmacSha256};
+/// use codeq::{ChecksumKey, ChecksumOptions, Encode, WithChecksum};
+///
+/// let options = ChecksumOptions {
+///     key: Some(ChecksumKey::new([7; 32])),
+///     ..ChecksumOptions::default()
+/// };
+///
+/// let mut buf = Vec::new();
+/// HmacSha256::wrap(5u64).encode_with_options(&options, &mut buf).unwrap();
+///
+/// let got = WithChecksum::<HmacSha256, u64>::decode_with_options(&options, buf.as_slice());
+/// assert_eq!(got.unwrap().into_inner(), 5);
+///
+/// // Without the key
+/// assert!(HmacSha256::wrap(5u64).encode_to_vec().is_err());
+/// ```
+/// 
+/// [`ChecksumWriter::new()`]: crate::ChecksumWriter::new
+/// [`ChecksumWriter::with_options()`]: crate::ChecksumWriter::with_options
+/// [`ChecksumReader::with_options()`]: crate::ChecksumReader::with_options
+/// [`WithChecksum`]: crate::WithChecksum
+/// [`Segment`]: crate::Segment
+/// [`Seeded`]: crate::config::Seeded
+#[derive(Debug, Clone, Default)]
+pub struct ChecksumOptions {
+    /// The secret key of a keyed checksum, such as `HmacSha256` and `Blake3Keyed`, which fail
+    /// with [`Error::MissingChecksumKey`](crate::Error::MissingChecksumKey) without one. Other
+    /// checksums ignore it.
+    pub key: Option<ChecksumKey>,
+
+    /// A per-file salt mixed into the checksum of [`Seeded`](crate::config::Seeded) configs,
+    /// e.g., a random value stored in the file header, so that a record copied from another file
+    /// fails verification. Other checksums ignore it.
+    pub salt: u64,
+}
@@ -1,18 +1,19 @@
 use std::fmt;
-use std::hash::Hasher;
 use std::io;
 
-use byteorder::BigEndian;
-use byteorder::ReadBytesExt;
-
+use crate::config::Checksum;
 use crate::config::CodeqConfig;
+use crate::config::UnkeyedChecksum;
+use crate::ChecksumOptions;
+use crate::Decode;
+use crate::Encode;
 
-const CHECKSUM_BYTES: usize = size_of::<u64>();
-
-/// A reader wrapper that calculates CRC32 checksum while reading data.
+/// A reader wrapper that calculates the checksum of the configured `C::Hasher` while reading
+/// data.
 ///
 /// This reader wraps any type implementing `io::Read` and transparently calculates
-/// a CRC32 checksum of all data read through it. The checksum can be either:
+/// the checksum of the configured `C::Hasher` of all data read through it. The checksum can be
+/// either:
 /// - Retrieved using [`finalize_checksum()`](Self::finalize_checksum)
 /// - Verified against an expected value using [`verify_checksum()`](Self::verify_checksum)
 /// The number of bytes read can be retrieved using [`read_bytes()`](Self::read_bytes).
@@ -45,6 +46,7 @@ const CHECKSUM_BYTES: usize = size_of::<u64>();
 /// ```ignore
 /// let reader = Crc32fast::new_reader(Vec::new());
 /// ```
+/// or with [`ChecksumReader::with_options`] for a checksum that needs a key.
 pub struct ChecksumReader<C, R>
 where C: CodeqConfig
 {
@@ -59,52 +61,75 @@ where
     R: io::Read,
 {
     /// Creates a new `ChecksumReader` wrapping the provided reader.
-    pub fn new(inner: R) -> Self {
+    ///
+    /// Only for a checksum that needs no key; see [`Self::with_options()`] otherwise.
+    pub fn new(inner: R) -> Self
+    where C::Hasher: UnkeyedChecksum {
         Self {
-            hasher: C::Hasher::default(),
+            hasher: <C::Hasher as UnkeyedChecksum>::new_for_verify(),
             inner,
             read: 0,
         }
     }
 
+    /// Creates a new `ChecksumReader` wrapping the provided reader, with the given options.
+    ///
+    /// Fails with [`Error::MissingChecksumKey`](crate::Error::MissingChecksumKey) if the
+    /// checksum is keyed and `options` has no key.
+    pub fn with_options(inner: R, options: &ChecksumOptions) -> io::Result<Self> {
+        Ok(Self {
+            hasher: C::Hasher::with_options_for_verify(options)?,
+            inner,
+            read: 0,
+        })
+    }
+
     /// Returns the number of bytes read through this reader.
     pub fn read_bytes(&self) -> usize {
         self.read
     }
 
-    /// Consumes the reader and returns the calculated CRC32 checksum.
+    /// Consumes the reader and returns the calculated checksum.
     ///
-    /// The returned value is the CRC32 checksum of all data read through this reader.
+    /// The returned value is the checksum of the configured `C::Hasher` of all data read through
+    /// this reader.
     #[allow(dead_code)]
-    pub fn finalize_checksum(self) -> u64 {
-        self.hasher.finish()
+    pub fn finalize_checksum(self) -> <C::Hasher as Checksum>::Output {
+        self.hasher.finalize()
     }
 
     /// Verifies the calculated checksum against an expected value stored in the stream.
     ///
-    /// Reads another 8-byte value from the underlying reader and compares
-    /// its least significant 32 bits with the calculated checksum. The `context` closure
-    /// is called to provide additional context in case of checksum mismatch.
+    /// Reads the stored checksum from the underlying reader and compares it with the calculated
+    /// checksum, see [`Checksum::verify()`]. The `context` closure is called to provide additional
+    /// context in case of checksum mismatch.
     ///
     /// # Errors
-    /// Returns [`io::Error`] with [`io::ErrorKind::InvalidData`] kind if checksums don't match.
+    /// Returns [`io::Error`] with [`io::ErrorKind::InvalidData`] kind if checksums don't match,
+    /// carrying an [`Error::ChecksumMismatch`](crate::Error::ChecksumMismatch) with the
+    /// algorithm, the stored and computed checksums and the number of bytes covered.
     pub fn verify_checksum<D: fmt::Display>(self, context: impl Fn() -> D) -> io::Result<usize> {
         let mut r = self.inner;
-        let actual = self.hasher.finish();
-
-        let got = r.read_u64::<BigEndian>()?;
-        if actual != got {
-            Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!(
-                    "crc32 checksum mismatch: expected {:x}, got {:x}, while {}",
-                    actual,
-                    got,
-                    context()
-                ),
-            ))
-        } else {
-            Ok(self.read + CHECKSUM_BYTES)
+
+        let stored = Decode::decode_with_order(C::BYTE_ORDER, &mut r)?;
+        let read = self.read;
+
+        match self.hasher.verify(&stored) {
+            Ok(()) => Ok(read + C::checksum_size()),
+            Err(computed) => {
+                let (mut stored_bytes, mut computed_bytes) = (Vec::new(), Vec::new());
+                stored.encode_with_order(C::BYTE_ORDER, &mut stored_bytes)?;
+                computed.encode_with_order(C::BYTE_ORDER, &mut computed_bytes)?;
+
+                Err(crate::Error::ChecksumMismatch {
+                    algorithm: C::Hasher::stored_algorithm(&stored).unwrap_or(C::ALGORITHM),
+                    stored: stored_bytes,
+                    computed: computed_bytes,
+                    bytes: read as u64,
+                    context: context().to_string(),
+                }
+                .into())
+            }
         }
     }
 }
@@ -117,7 +142,7 @@ where
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         let read = self.inner.read(buf)?;
         if read > 0 {
-            self.hasher.write(&buf[..read]);
+            self.hasher.update(&buf[..read]);
         }
         self.read += read;
         Ok(read)
@@ -134,6 +159,7 @@ mod tests_crc32fast {
 
     use crate::config::CodeqConfig;
     use crate::config::Crc32fast;
+    use crate::Error;
 
     #[test]
     fn test_checksum_reader() -> anyhow::Result<()> {
@@ -184,6 +210,43 @@ mod tests_crc32fast {
         Ok(())
     }
 
+    #[test]
+    fn test_checksum_mismatch_error() -> anyhow::Result<()> {
+        let mut b = Vec::new();
+        let mut w = Crc32fast::new_writer(&mut b);
+        w.write_all(b"foo")?;
+        w.write_checksum()?;
+
+        let last = b.len() - 1;
+        b[last] ^= 1;
+
+        let mut r = Crc32fast::new_reader(&b[..]);
+        r.read_exact(&mut [0u8; 3])?;
+        let err = r.verify_checksum(|| "read foo").unwrap_err();
+
+        let computed = crc32fast::hash(b"foo") as u64;
+        assert_eq!(
+            Some(&Error::ChecksumMismatch {
+                algorithm: "crc32",
+                stored: (computed ^ 1).to_be_bytes().to_vec(),
+                computed: computed.to_be_bytes().to_vec(),
+                bytes: 3,
+                context: "read foo".to_string(),
+            }),
+            Error::from_io(&err)
+        );
+        assert_eq!(
+            format!(
+                "crc32 checksum mismatch: stored {:#018x}, computed {:#018x}, over 3 bytes, while read foo",
+                computed ^ 1,
+                computed
+            ),
+            err.to_string()
+        );
+
+        Ok(())
+    }
+
     struct OneByteReader<R>(R);
 
     impl<R: Read> Read for OneByteReader<R> {
@@ -246,7 +309,7 @@ mod tests_crc64fast_nvme {
             let mut read_buf = [0u8; 6];
             r.read_exact(&mut read_buf)?;
             let crc = r.finalize_checksum();
-            assert_eq!(Crc64fastNvme::hash(b"foobar") as u64, crc);
+            assert_eq!(Crc64fastNvme::hash(b"foobar"), crc);
         }
 
         // Verify the checksum
@@ -266,7 +329,8 @@ mod tests_crc64fast_nvme {
             let mut read_buf = [0; 6];
             r.read_exact(&mut read_buf)?;
             let res = r.verify_checksum(|| ""); // checksum error
-            assert!(res.is_err());
+            let err = res.unwrap_err();
+            assert!(err.to_string().starts_with("crc64-nvme checksum mismatch: "));
         }
 
         Ok(())
@@ -1,15 +1,16 @@
-use std::hash::Hasher;
 use std::io;
 
-use byteorder::BigEndian;
-use byteorder::WriteBytesExt;
-
+use crate::config::Checksum;
 use crate::config::CodeqConfig;
+use crate::config::UnkeyedChecksum;
+use crate::ChecksumOptions;
+use crate::Encode;
 
-/// A writer that calculates CRC32 checksum while writing data.
+/// A writer that calculates the checksum of the configured `C::Hasher` while writing data.
 ///
 /// This writer wraps any type implementing [`io::Write`] and transparently calculates
-/// a CRC32 checksum of all data written through it. The checksum can be either:
+/// the checksum of the configured `C::Hasher` of all data written through it. The checksum can be
+/// either:
 /// - Retrieved using `finalize_checksum()`
 /// - Written to the underlying writer using `write_checksum()`
 /// - Written with `finalize()`, which returns the total bytes written
@@ -31,6 +32,7 @@ use crate::config::CodeqConfig;
 /// ```ignore
 /// let writer = Crc32fast::new_writer(Vec::new());
 /// ```
+/// or with [`ChecksumWriter::with_options`] for a checksum that needs a key.
 pub struct ChecksumWriter<C, W>
 where C: CodeqConfig
 {
@@ -45,40 +47,51 @@ where
     W: io::Write,
 {
     /// Create a new [`ChecksumWriter`] that wraps the provided writer.
-    pub fn new(inner: W) -> Self {
+    ///
+    /// Only for a checksum that needs no key; see [`Self::with_options()`] otherwise.
+    pub fn new(inner: W) -> Self
+    where C::Hasher: UnkeyedChecksum {
         Self {
-            hasher: Default::default(),
+            hasher: <C::Hasher as UnkeyedChecksum>::new(),
             inner,
             written: 0,
         }
     }
 
-    /// Finalize the crc32 checksum and consume `self`.
+    /// Create a new [`ChecksumWriter`] that wraps the provided writer, with the given options.
+    ///
+    /// Fails with [`Error::MissingChecksumKey`](crate::Error::MissingChecksumKey) if the
+    /// checksum is keyed and `options` has no key.
+    pub fn with_options(inner: W, options: &ChecksumOptions) -> io::Result<Self> {
+        Ok(Self {
+            hasher: C::Hasher::with_options(options)?,
+            inner,
+            written: 0,
+        })
+    }
+
+    /// Finalize the checksum and consume `self`.
     ///
     /// Return the checksum of all written data.
     #[allow(dead_code)]
-    pub fn finalize_checksum(self) -> u64 {
-        self.hasher.finish()
+    pub fn finalize_checksum(self) -> <C::Hasher as Checksum>::Output {
+        self.hasher.finalize()
     }
 
-    /// Append the finalized crc32 checksum in the least significant 32 bits of a `u64` to the its
-    /// inner writer, in BigEndian.
+    /// Append the finalized checksum to the its inner writer, in the byte order of `C`.
     ///
     /// Returns the number of bytes written.
     pub fn write_checksum(self) -> io::Result<usize> {
         let mut w = self.inner;
-        let crc = self.hasher.finish();
-        w.write_u64::<BigEndian>(crc)?;
-        Ok(8)
+        let checksum = self.hasher.finalize();
+        checksum.encode_with_order(C::BYTE_ORDER, &mut w)
     }
 
     /// Append the finalized checksum to the inner writer and return the total
     /// bytes written, including the checksum.
     pub fn finalize(self) -> io::Result<usize> {
-        let mut w = self.inner;
-        let crc = self.hasher.finish();
-        w.write_u64::<BigEndian>(crc)?;
-        Ok(self.written + 8)
+        let written = self.written;
+        Ok(written + self.write_checksum()?)
     }
 }
 
@@ -89,7 +102,7 @@ where
 {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         let written = self.inner.write(buf)?;
-        self.hasher.write(&buf[..written]);
+        self.hasher.update(&buf[..written]);
         self.written += written;
         Ok(written)
     }
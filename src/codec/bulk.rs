@@ -0,0 +1,119 @@
+//! Bulk encoding and decoding of [`FixedSize`] elements.
+//!
+//! Elements are staged through a buffer so that the underlying reader or writer is called once
+//! per chunk instead of once per element. Fixed-size values are read with [`read_exact()`], which
+//! reports a truncated input as [`Error::UnexpectedEof`].
+
+use std::io;
+
+use crate::decode_limits;
+use crate::error_context_ext::ErrorContextExt;
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::Error;
+use crate::FixedSize;
+
+/// Max number of bytes staged in a buffer at a time.
+const CHUNK_BYTES: usize = 64 * 1024;
+
+/// Number of elements of `T` in a chunk, at least 1.
+fn chunk_len<T: FixedSize>() -> usize {
+    (CHUNK_BYTES / T::encoded_size().max(1)).max(1)
+}
+
+/// Reads into `buf` until it is full or the input ends, and returns the number of bytes read.
+fn fill<R: io::Read>(mut r: R, buf: &mut [u8]) -> Result<usize, io::Error> {
+    let mut n = 0;
+    while n < buf.len() {
+        match r.read(&mut buf[n..]) {
+            Ok(0) => break,
+            Ok(k) => n += k,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(n)
+}
+
+/// Like [`io::Read::read_exact()`], but fails with [`Error::UnexpectedEof`], which tells how many
+/// bytes were read, if the input ends before `buf` is full.
+pub(crate) fn read_exact<R: io::Read>(r: R, buf: &mut [u8]) -> Result<(), io::Error> {
+    let n = fill(r, buf)?;
+    if n < buf.len() {
+        return Err(Error::UnexpectedEof {
+            read: n as u64,
+            expected: buf.len() as u64,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Encodes elements into a buffer chunk by chunk and writes each chunk at once.
+pub(crate) fn encode_fixed_size_slice<T, W>(
+    items: &[T],
+    order: ByteOrder,
+    mut w: W,
+) -> Result<usize, io::Error>
+where
+    T: Encode + FixedSize,
+    W: io::Write,
+{
+    let mut n = 0;
+    let mut buf = Vec::with_capacity(items.len().min(chunk_len::<T>()) * T::encoded_size());
+
+    for chunk in items.chunks(chunk_len::<T>()) {
+        buf.clear();
+        for item in chunk {
+            item.encode_with_order(order, &mut buf)?;
+        }
+        w.write_all(&buf)?;
+        n += buf.len();
+    }
+
+    Ok(n)
+}
+
+/// Reads elements chunk by chunk and decodes each chunk from the buffer.
+pub(crate) fn decode_fixed_size_vec<T, R>(
+    len: usize,
+    order: ByteOrder,
+    mut r: R,
+) -> Result<Vec<T>, io::Error>
+where
+    T: Decode + FixedSize,
+    R: io::Read,
+{
+    decode_limits::reserve::<T>(len)?;
+
+    let mut v = Vec::with_capacity(decode_limits::initial_capacity::<T>(len));
+    let mut buf = vec![0; len.min(chunk_len::<T>()) * T::encoded_size()];
+
+    let mut remaining = len;
+    while remaining > 0 {
+        let k = remaining.min(chunk_len::<T>());
+        let chunk = &mut buf[..k * T::encoded_size()];
+
+        let n = fill(&mut r, chunk)?;
+        if n < chunk.len() {
+            let err: io::Error = Error::UnexpectedEof {
+                read: (v.len() * T::encoded_size() + n) as u64,
+                expected: (len * T::encoded_size()) as u64,
+            }
+            .into();
+            // The first element not read completely
+            return Err(err.in_path(format!("[{}]", v.len() + n / T::encoded_size())));
+        }
+
+        let mut slice = &chunk[..];
+        for _ in 0..k {
+            let item = T::decode_with_order(order, &mut slice)
+                .map_err(|e| e.in_path(format!("[{}]", v.len())))?;
+            v.push(item);
+        }
+        remaining -= k;
+    }
+
+    Ok(v)
+}
@@ -1,5 +1,10 @@
 use std::io;
 
+use crate::codec::encode::unsupported_byte_order;
+use crate::decode_limits;
+use crate::error_context_ext::ErrorContextExt;
+use crate::ByteOrder;
+
 /// A trait that can be decoded from an [`io::Read`] stream.
 ///
 /// Implementing this trait allows types to be decoded from an [`io::Read`] stream,
@@ -14,5 +19,50 @@ use std::io;
 /// assert_eq!(decoded, "hello");
 /// ```
 pub trait Decode: Sized {
+    /// Decodes a value encoded in big-endian byte order.
     fn decode<R: io::Read>(r: R) -> Result<Self, io::Error>;
+
+    /// Decodes a value encoded in byte order `order`, the counterpart of
+    /// [`Encode::encode_with_order`].
+    ///
+    /// The default implementation calls `decode()` for big-endian and fails with
+    /// [`Error::UnsupportedByteOrder`] otherwise.
+    ///
+    /// [`Encode::encode_with_order`]: crate::Encode::encode_with_order
+    /// [`Error::UnsupportedByteOrder`]: crate::Error::UnsupportedByteOrder
+    fn decode_with_order<R: io::Read>(order: ByteOrder, r: R) -> Result<Self, io::Error> {
+        match order {
+            ByteOrder::BigEndian => Self::decode(r),
+            _ => Err(unsupported_byte_order::<Self>(order)),
+        }
+    }
+
+    /// Decodes `len` values encoded one after another in byte order `order`, without a length
+    /// prefix.
+    ///
+    /// This is the counterpart of [`Encode::encode_slice`], used by sequence codecs such as
+    /// `Vec<T>`. The default implementation decodes each element in turn; types with a cheaper
+    /// bulk form override it.
+    ///
+    /// `len` is checked against the current [`DecodeLimits`] and the `Vec` grows as elements are
+    /// read, so a corrupted `len` does not allocate memory up front.
+    ///
+    /// [`DecodeLimits`]: crate::DecodeLimits
+    /// [`Encode::encode_slice`]: crate::Encode::encode_slice
+    fn decode_vec<R: io::Read>(
+        len: usize,
+        order: ByteOrder,
+        mut r: R,
+    ) -> Result<Vec<Self>, io::Error> {
+        decode_limits::reserve::<Self>(len)?;
+
+        let mut v = Vec::with_capacity(decode_limits::initial_capacity::<Self>(len));
+        for i in 0..len {
+            v.push(
+                Self::decode_with_order(order, &mut r)
+                    .map_err(|e| e.in_path(format!("[{}]", i)))?,
+            );
+        }
+        Ok(v)
+    }
 }
@@ -2,6 +2,8 @@ use std::io;
 use std::io::Error;
 use std::io::Write;
 
+use crate::ByteOrder;
+
 /// A trait that can be encoded into an [`io::Write`] stream.
 ///
 /// Implementing this trait allows types to be encoded into an [`io::Write`] stream,
@@ -17,8 +19,48 @@ use std::io::Write;
 /// assert_eq!(buf, b"\x00\x00\x00\x05hello");
 /// ```
 pub trait Encode: Sized {
+    /// Encodes the value in big-endian byte order.
     fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error>;
 
+    /// Encodes the value with multi-byte integers, floats, length prefixes and enum tags in the
+    /// byte order `order`, passing it on to the values it contains.
+    ///
+    /// This is how [`WithChecksum`] and [`Segment`] encode in the byte order of their
+    /// [`CodeqConfig`]. The built-in and derived impls implement it, and `encode()` calls it
+    /// with [`ByteOrder::BigEndian`]. The default implementation calls `encode()` for big-endian
+    /// and fails with [`Error::UnsupportedByteOrder`] otherwise, so that a hand-written impl
+    /// never silently writes big-endian data into a little-endian format.
+    ///
+    /// [`WithChecksum`]: crate::WithChecksum
+    /// [`Segment`]: crate::Segment
+    /// [`CodeqConfig`]: crate::config::CodeqConfig
+    /// [`Error::UnsupportedByteOrder`]: crate::Error::UnsupportedByteOrder
+    fn encode_with_order<W: io::Write>(&self, order: ByteOrder, w: W) -> Result<usize, io::Error> {
+        match order {
+            ByteOrder::BigEndian => self.encode(w),
+            _ => Err(unsupported_byte_order::<Self>(order)),
+        }
+    }
+
+    /// Encodes a slice of values one after another in byte order `order`, without a length
+    /// prefix.
+    ///
+    /// This is how sequence codecs such as `Vec<T>` encode their elements. The default
+    /// implementation encodes each element in turn; types with a cheaper bulk form override it,
+    /// e.g., `u8` writes the whole slice at once. An override must produce exactly the same bytes
+    /// as the default implementation.
+    fn encode_slice<W: io::Write>(
+        items: &[Self],
+        order: ByteOrder,
+        mut w: W,
+    ) -> Result<usize, io::Error> {
+        let mut n = 0;
+        for item in items {
+            n += item.encode_with_order(order, &mut w)?;
+        }
+        Ok(n)
+    }
+
     /// Returns the leading type id when the encoded form starts with one.
     fn type_id(&self) -> Option<u32> {
         None
@@ -35,11 +77,24 @@ pub trait Encode: Sized {
     }
 }
 
+/// The error of encoding or decoding `T` in a byte order its codec does not implement.
+pub(crate) fn unsupported_byte_order<T>(order: ByteOrder) -> io::Error {
+    crate::Error::UnsupportedByteOrder {
+        type_name: std::any::type_name::<T>(),
+        order,
+    }
+    .into()
+}
+
 impl<T: Encode> Encode for &T {
     fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
         (*self).encode(w)
     }
 
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, w: W) -> Result<usize, Error> {
+        (*self).encode_with_order(order, w)
+    }
+
     fn type_id(&self) -> Option<u32> {
         (*self).type_id()
     }
@@ -51,6 +106,7 @@ mod tests {
     use std::io::Write;
 
     use crate::codec::Encode;
+    use crate::ByteOrder;
 
     struct Foo;
 
@@ -74,6 +130,21 @@ mod tests {
         assert_eq!(n, 3);
     }
 
+    #[test]
+    fn test_encode_with_order_default() {
+        let n = Foo.encode_with_order(ByteOrder::BigEndian, Vec::new()).unwrap();
+        assert_eq!(n, 3);
+
+        let err = Foo.encode_with_order(ByteOrder::LittleEndian, Vec::new()).unwrap_err();
+        assert_eq!(
+            Some(&crate::Error::UnsupportedByteOrder {
+                type_name: "codeq::codec::encode::tests::Foo",
+                order: ByteOrder::LittleEndian
+            }),
+            crate::Error::from_io(&err)
+        );
+    }
+
     #[test]
     fn test_encode_to_vec() {
         let buf = 258u32.encode_to_vec().unwrap();
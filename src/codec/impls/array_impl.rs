@@ -0,0 +1,107 @@
+use std::io;
+
+use crate::error_context_ext::ErrorContextExt;
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+impl<T: FixedSize, const N: usize> FixedSize for [T; N] {
+    fn encoded_size() -> usize {
+        T::encoded_size() * N
+    }
+}
+
+/// The `N` elements without a length prefix, since the length is part of the type.
+impl<T: Encode, const N: usize> Encode for [T; N] {
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(&self, order: ByteOrder, w: W) -> Result<usize, io::Error> {
+        T::encode_slice(self, order, w)
+    }
+}
+
+impl<T: Decode, const N: usize> Decode for [T; N] {
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    /// Decodes the elements in place. An array is not allocated on the heap, so it is not
+    /// counted against the [`DecodeLimits`](crate::DecodeLimits).
+    fn decode_with_order<R: io::Read>(order: ByteOrder, mut r: R) -> Result<Self, io::Error> {
+        let mut res = Ok(());
+
+        let arr: [Option<T>; N] = std::array::from_fn(|i| {
+            res.as_ref().ok()?;
+
+            T::decode_with_order(order, &mut r)
+                .map_err(|e| res = Err(e.in_path(format!("[{}]", i))))
+                .ok()
+        });
+
+        res?;
+        Ok(arr.map(|v| v.expect("every element is decoded")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::Decode;
+    use crate::DecodeLimits;
+    use crate::Encode;
+    use crate::FixedSize;
+
+    #[test]
+    fn test_array_codec() -> Result<(), io::Error> {
+        assert_eq!(6, <[u16; 3]>::encoded_size());
+        assert_eq!(0, <[u64; 0]>::encoded_size());
+
+        let a = [1u16, 2, 3];
+        let buf = a.encode_to_vec()?;
+        assert_eq!(vec![0, 1, 0, 2, 0, 3], buf);
+        assert_eq!(a, <[u16; 3]>::decode(&mut buf.as_slice())?);
+
+        let a = [7u8; 4];
+        let buf = a.encode_to_vec()?;
+        assert_eq!(vec![7, 7, 7, 7], buf);
+        assert_eq!(a, <[u8; 4]>::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_of_variable_size() -> Result<(), io::Error> {
+        let a = ["a".to_string(), "b".to_string()];
+        let buf = a.encode_to_vec()?;
+        assert_eq!(vec![0, 0, 0, 1, b'a', 0, 0, 0, 1, b'b'], buf);
+        assert_eq!(a, <[String; 2]>::decode(&mut buf.as_slice())?);
+
+        let err = <[u8; 4]>::decode(&mut [1u8, 2].as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_not_limited() -> Result<(), io::Error> {
+        let limits = DecodeLimits {
+            max_len: 2,
+            max_total_alloc: 0,
+            ..DecodeLimits::default()
+        };
+
+        let a = [1u64, 2, 3];
+        let buf = a.encode_to_vec()?;
+        assert_eq!(a, limits.scope(|| <[u64; 3]>::decode(&mut buf.as_slice()))?);
+
+        let err = <[u16; 3]>::decode(&mut [0u8, 1, 0].as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+        assert!(err.to_string().ends_with("at:([1])"), "{}", err);
+
+        Ok(())
+    }
+}
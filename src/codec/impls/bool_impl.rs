@@ -1,8 +1,9 @@
 use std::io;
 
-use byteorder::ReadBytesExt;
 use byteorder::WriteBytesExt;
 
+use crate::codec::bulk;
+use crate::ByteOrder;
 use crate::Decode;
 use crate::Encode;
 use crate::FixedSize;
@@ -18,19 +19,43 @@ impl Encode for bool {
         w.write_u8(if *self { 1 } else { 0 })?;
         Ok(1)
     }
+
+    /// A single byte, the same in either byte order.
+    fn encode_with_order<W: io::Write>(&self, _order: ByteOrder, w: W) -> Result<usize, io::Error> {
+        self.encode(w)
+    }
+
+    fn encode_slice<W: io::Write>(
+        items: &[Self],
+        order: ByteOrder,
+        w: W,
+    ) -> Result<usize, io::Error> {
+        bulk::encode_fixed_size_slice(items, order, w)
+    }
 }
 
 impl Decode for bool {
-    fn decode<R: io::Read>(mut r: R) -> Result<Self, io::Error> {
-        let b = r.read_u8()?;
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        let mut buf = [0; 1];
+        bulk::read_exact(r, &mut buf)?;
+        let b = buf[0];
         if b > 1 {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Invalid bool value: {}", b),
-            ));
+            return Err(crate::Error::InvalidValue {
+                type_name: "bool",
+                value: b.to_string(),
+            }
+            .into());
         }
         Ok(b != 0)
     }
+
+    fn decode_with_order<R: io::Read>(_order: ByteOrder, r: R) -> Result<Self, io::Error> {
+        Self::decode(r)
+    }
+
+    fn decode_vec<R: io::Read>(len: usize, order: ByteOrder, r: R) -> Result<Vec<Self>, io::Error> {
+        bulk::decode_fixed_size_vec(len, order, r)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,95 @@
+use std::io;
+use std::mem::size_of;
+
+use byteorder::ByteOrder as _;
+use byteorder::WriteBytesExt;
+
+use crate::byte_order::with_byte_order;
+use crate::codec::bulk;
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+impl FixedSize for char {
+    fn encoded_size() -> usize {
+        size_of::<u32>()
+    }
+}
+
+/// Encodes the Unicode scalar value as a big-endian `u32`.
+impl Encode for char {
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(
+        &self,
+        order: ByteOrder,
+        mut w: W,
+    ) -> Result<usize, io::Error> {
+        with_byte_order!(order, O => w.write_u32::<O>(*self as u32))?;
+        Ok(Self::encoded_size())
+    }
+
+    fn encode_slice<W: io::Write>(
+        items: &[Self],
+        order: ByteOrder,
+        w: W,
+    ) -> Result<usize, io::Error> {
+        bulk::encode_fixed_size_slice(items, order, w)
+    }
+}
+
+impl Decode for char {
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: io::Read>(order: ByteOrder, mut r: R) -> Result<Self, io::Error> {
+        let mut buf = [0; size_of::<u32>()];
+        bulk::read_exact(&mut r, &mut buf)?;
+        let v = with_byte_order!(order, O => O::read_u32(&buf));
+        char::from_u32(v).ok_or_else(|| {
+            crate::Error::InvalidValue {
+                type_name: "char",
+                value: format!("{:#x}", v),
+            }
+            .into()
+        })
+    }
+
+    fn decode_vec<R: io::Read>(len: usize, order: ByteOrder, r: R) -> Result<Vec<Self>, io::Error> {
+        bulk::decode_fixed_size_vec(len, order, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::testing::test_int_coded;
+    use crate::Decode;
+    use crate::Encode;
+
+    #[test]
+    fn test_char_codec() -> anyhow::Result<()> {
+        test_int_coded('a')?;
+        test_int_coded('你')?;
+        test_int_coded(char::MAX)?;
+
+        assert_eq!(vec![0, 0, 0x4f, 0x60], '你'.encode_to_vec()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_char_decode_invalid() -> anyhow::Result<()> {
+        for v in [0xd800u32, 0xdfff, 0x11_0000, u32::MAX] {
+            let buf = v.encode_to_vec()?;
+            let err = char::decode(&mut buf.as_slice()).unwrap_err();
+            assert_eq!(io::ErrorKind::InvalidData, err.kind());
+            assert_eq!(format!("Invalid char value: {:#x}", v), err.to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+//! `Cow` is encoded exactly like the borrowed form, and always decoded into the owned form.
+
+use std::borrow::Cow;
+use std::io;
+
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+
+impl Encode for Cow<'_, str> {
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(&self, order: ByteOrder, w: W) -> Result<usize, io::Error> {
+        self.as_ref().encode_with_order(order, w)
+    }
+}
+
+impl Decode for Cow<'_, str> {
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: io::Read>(order: ByteOrder, r: R) -> Result<Self, io::Error> {
+        Ok(Cow::Owned(String::decode_with_order(order, r)?))
+    }
+}
+
+impl<T: Encode + Clone> Encode for Cow<'_, [T]> {
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(&self, order: ByteOrder, w: W) -> Result<usize, io::Error> {
+        self.as_ref().encode_with_order(order, w)
+    }
+}
+
+impl<T: Decode + Clone> Decode for Cow<'_, [T]> {
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: io::Read>(order: ByteOrder, r: R) -> Result<Self, io::Error> {
+        Ok(Cow::Owned(Vec::<T>::decode_with_order(order, r)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::io;
+
+    use crate::Decode;
+    use crate::Encode;
+
+    #[test]
+    fn test_cow_str_codec() -> Result<(), io::Error> {
+        let want = "foo".encode_to_vec()?;
+
+        for c in [Cow::Borrowed("foo"), Cow::Owned("foo".to_string())] {
+            let buf = c.encode_to_vec()?;
+            assert_eq!(want, buf);
+
+            let got = Cow::<str>::decode(&mut buf.as_slice())?;
+            assert!(matches!(got, Cow::Owned(_)));
+            assert_eq!(c, got);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cow_bytes_codec() -> Result<(), io::Error> {
+        let want = vec![1u8, 2, 3].encode_to_vec()?;
+
+        for c in [Cow::Borrowed(&[1u8, 2, 3][..]), Cow::Owned(vec![1u8, 2, 3])] {
+            let buf = c.encode_to_vec()?;
+            assert_eq!(want, buf);
+            assert_eq!(c, Cow::<[u8]>::decode(&mut buf.as_slice())?);
+        }
+
+        Ok(())
+    }
+}
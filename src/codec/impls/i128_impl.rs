@@ -0,0 +1,69 @@
+use std::io;
+use std::mem::size_of;
+
+use byteorder::ByteOrder as _;
+use byteorder::WriteBytesExt;
+
+use crate::byte_order::with_byte_order;
+use crate::codec::bulk;
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+impl FixedSize for i128 {
+    fn encoded_size() -> usize {
+        size_of::<Self>()
+    }
+}
+
+impl Encode for i128 {
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(
+        &self,
+        order: ByteOrder,
+        mut w: W,
+    ) -> Result<usize, io::Error> {
+        with_byte_order!(order, O => w.write_i128::<O>(*self))?;
+        Ok(Self::encoded_size())
+    }
+
+    fn encode_slice<W: io::Write>(
+        items: &[Self],
+        order: ByteOrder,
+        w: W,
+    ) -> Result<usize, io::Error> {
+        bulk::encode_fixed_size_slice(items, order, w)
+    }
+}
+
+impl Decode for i128 {
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: io::Read>(order: ByteOrder, mut r: R) -> Result<Self, io::Error> {
+        let mut buf = [0; size_of::<Self>()];
+        bulk::read_exact(&mut r, &mut buf)?;
+        let v = with_byte_order!(order, O => O::read_i128(&buf));
+        Ok(v)
+    }
+
+    fn decode_vec<R: io::Read>(len: usize, order: ByteOrder, r: R) -> Result<Vec<Self>, io::Error> {
+        bulk::decode_fixed_size_vec(len, order, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::testing::test_int_coded;
+
+    #[test]
+    fn test_i128_codec() -> anyhow::Result<()> {
+        test_int_coded(-0x1234567890abcdef1234567890abcdefi128)
+    }
+}
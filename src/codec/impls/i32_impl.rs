@@ -0,0 +1,77 @@
+use std::io;
+use std::mem::size_of;
+
+use byteorder::ByteOrder as _;
+use byteorder::WriteBytesExt;
+
+use crate::byte_order::with_byte_order;
+use crate::codec::bulk;
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+impl FixedSize for i32 {
+    fn encoded_size() -> usize {
+        size_of::<Self>()
+    }
+}
+
+impl Encode for i32 {
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(
+        &self,
+        order: ByteOrder,
+        mut w: W,
+    ) -> Result<usize, io::Error> {
+        with_byte_order!(order, O => w.write_i32::<O>(*self))?;
+        Ok(Self::encoded_size())
+    }
+
+    fn encode_slice<W: io::Write>(
+        items: &[Self],
+        order: ByteOrder,
+        w: W,
+    ) -> Result<usize, io::Error> {
+        bulk::encode_fixed_size_slice(items, order, w)
+    }
+}
+
+impl Decode for i32 {
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: io::Read>(order: ByteOrder, mut r: R) -> Result<Self, io::Error> {
+        let mut buf = [0; size_of::<Self>()];
+        bulk::read_exact(&mut r, &mut buf)?;
+        let v = with_byte_order!(order, O => O::read_i32(&buf));
+        Ok(v)
+    }
+
+    fn decode_vec<R: io::Read>(len: usize, order: ByteOrder, r: R) -> Result<Vec<Self>, io::Error> {
+        bulk::decode_fixed_size_vec(len, order, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::testing::test_int_coded;
+    use crate::Encode;
+
+    #[test]
+    fn test_i32_codec() -> anyhow::Result<()> {
+        test_int_coded(-0x12345678i32)
+    }
+
+    #[test]
+    fn test_i32_two_complement_big_endian() -> anyhow::Result<()> {
+        assert_eq!(vec![0xff, 0xff, 0xff, 0xfe], (-2i32).encode_to_vec()?);
+        assert_eq!(vec![0x80, 0, 0, 0], i32::MIN.encode_to_vec()?);
+        Ok(())
+    }
+}
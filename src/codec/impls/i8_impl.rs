@@ -0,0 +1,64 @@
+use std::io;
+use std::mem::size_of;
+
+use byteorder::WriteBytesExt;
+
+use crate::codec::bulk;
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+impl FixedSize for i8 {
+    fn encoded_size() -> usize {
+        size_of::<Self>()
+    }
+}
+
+impl Encode for i8 {
+    fn encode<W: io::Write>(&self, mut w: W) -> Result<usize, io::Error> {
+        w.write_i8(*self)?;
+        Ok(Self::encoded_size())
+    }
+
+    /// A single byte, the same in either byte order.
+    fn encode_with_order<W: io::Write>(&self, _order: ByteOrder, w: W) -> Result<usize, io::Error> {
+        self.encode(w)
+    }
+
+    fn encode_slice<W: io::Write>(
+        items: &[Self],
+        order: ByteOrder,
+        w: W,
+    ) -> Result<usize, io::Error> {
+        bulk::encode_fixed_size_slice(items, order, w)
+    }
+}
+
+impl Decode for i8 {
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        let mut buf = [0; 1];
+        bulk::read_exact(r, &mut buf)?;
+        let v = buf[0] as i8;
+        Ok(v)
+    }
+
+    fn decode_with_order<R: io::Read>(_order: ByteOrder, r: R) -> Result<Self, io::Error> {
+        Self::decode(r)
+    }
+
+    fn decode_vec<R: io::Read>(len: usize, order: ByteOrder, r: R) -> Result<Vec<Self>, io::Error> {
+        bulk::decode_fixed_size_vec(len, order, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::testing::test_int_coded;
+
+    #[test]
+    fn test_i8_codec() -> anyhow::Result<()> {
+        test_int_coded(-0x12i8)
+    }
+}
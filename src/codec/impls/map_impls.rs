@@ -0,0 +1,197 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+use std::io::Error;
+use std::io::Read;
+use std::io::Write;
+
+use crate::decode_limits;
+use crate::error_context_ext::ErrorContextExt;
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::LenPrefix;
+
+/// A `u32` entry count followed by the key-value pairs in ascending key order.
+///
+/// A decode error of the `i`-th entry has the path `[i].key` or `[i].value`.
+impl<K: Encode, V: Encode> Encode for BTreeMap<K, V> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        let mut n = u32::encode_len(self.len(), order, &mut w)?;
+        for (k, v) in self {
+            n += k.encode_with_order(order, &mut w)?;
+            n += v.encode_with_order(order, &mut w)?;
+        }
+        Ok(n)
+    }
+}
+
+impl<K: Decode + Ord, V: Decode> Decode for BTreeMap<K, V> {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        decode_limits::nested(|| {
+            let len = u32::decode_len(order, &mut r).in_path("len")?;
+            decode_limits::reserve::<(K, V)>(len)?;
+
+            let mut m = BTreeMap::new();
+            for i in 0..len {
+                let k = K::decode_with_order(order, &mut r)
+                    .map_err(|e| e.in_path("key").in_path(format!("[{}]", i)))?;
+                let v = V::decode_with_order(order, &mut r)
+                    .map_err(|e| e.in_path("value").in_path(format!("[{}]", i)))?;
+                if m.insert(k, v).is_some() {
+                    return Err(duplicate_key().in_path(format!("[{}]", i)));
+                }
+            }
+            Ok(m)
+        })
+    }
+}
+
+/// Encoded the same as a `BTreeMap` with the same content: entries are sorted by key, so that the
+/// output does not depend on the hasher state.
+impl<K: Encode + Ord, V: Encode, S> Encode for HashMap<K, V, S> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        let mut entries = self.iter().collect::<Vec<_>>();
+        entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+        let mut n = u32::encode_len(entries.len(), order, &mut w)?;
+        for (k, v) in entries {
+            n += k.encode_with_order(order, &mut w)?;
+            n += v.encode_with_order(order, &mut w)?;
+        }
+        Ok(n)
+    }
+}
+
+impl<K, V, S> Decode for HashMap<K, V, S>
+where
+    K: Decode + Eq + Hash,
+    V: Decode,
+    S: BuildHasher + Default,
+{
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        decode_limits::nested(|| {
+            let len = u32::decode_len(order, &mut r).in_path("len")?;
+            decode_limits::reserve::<(K, V)>(len)?;
+
+            let mut m = HashMap::with_hasher(S::default());
+            for i in 0..len {
+                let k = K::decode_with_order(order, &mut r)
+                    .map_err(|e| e.in_path("key").in_path(format!("[{}]", i)))?;
+                let v = V::decode_with_order(order, &mut r)
+                    .map_err(|e| e.in_path("value").in_path(format!("[{}]", i)))?;
+                if m.insert(k, v).is_some() {
+                    return Err(duplicate_key().in_path(format!("[{}]", i)));
+                }
+            }
+            Ok(m)
+        })
+    }
+}
+
+fn duplicate_key() -> Error {
+    crate::Error::DuplicateMapKey.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::collections::HashMap;
+    use std::io;
+
+    use crate::Decode;
+    use crate::Encode;
+    use crate::LocatedError;
+
+    #[test]
+    fn test_btree_map_codec() -> Result<(), io::Error> {
+        let m = BTreeMap::from([("b".to_string(), 2u8), ("a".to_string(), 1u8)]);
+
+        let buf = m.encode_to_vec()?;
+        assert_eq!(
+            vec![
+                0, 0, 0, 2, // count
+                0, 0, 0, 1, b'a', 1, //
+                0, 0, 0, 1, b'b', 2, //
+            ],
+            buf
+        );
+        assert_eq!(m, BTreeMap::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_map_codec_is_sorted() -> Result<(), io::Error> {
+        let entries = (0..100u64).rev().map(|i| (i, i as u8)).collect::<Vec<_>>();
+
+        let h = entries.iter().copied().collect::<HashMap<_, _>>();
+        let b = entries.iter().copied().collect::<BTreeMap<_, _>>();
+
+        let buf = h.encode_to_vec()?;
+        assert_eq!(b.encode_to_vec()?, buf);
+
+        // Same content built in another order yields the same bytes.
+        let h2 = entries.iter().rev().copied().collect::<HashMap<_, _>>();
+        assert_eq!(buf, h2.encode_to_vec()?);
+
+        assert_eq!(h, HashMap::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_decode_duplicate_key() -> Result<(), io::Error> {
+        let buf = vec![
+            0, 0, 0, 2, // count
+            1, 10, //
+            1, 11, //
+        ];
+
+        let err = BTreeMap::<u8, u8>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+        let err = HashMap::<u8, u8>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!("Duplicate map key; at:([1])", err.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_decode_error_path() -> Result<(), io::Error> {
+        let buf = vec![
+            0, 0, 0, 2, // count
+            1, 1, //
+            2, 5, // invalid bool value
+        ];
+        let err = BTreeMap::<u8, bool>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!("[1].value", LocatedError::from_io(&err).unwrap().path());
+
+        let buf = vec![
+            0, 0, 0, 1, // count
+            7, 1, // invalid bool key
+        ];
+        let err = HashMap::<bool, u8>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!("[0].key", LocatedError::from_io(&err).unwrap().path());
+
+        Ok(())
+    }
+}
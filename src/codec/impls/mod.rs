@@ -1,13 +1,74 @@
 //! Implementations of the [`Encode`] and [`Decode`] traits for various types.
 //!
+//! Integers are encoded in big-endian with their in-memory width. `usize` and `isize` are not
+//! implemented, because their width depends on the platform. Every multi-byte value below,
+//! including length prefixes and enum tags, uses the given byte order instead when encoded with
+//! [`Encode::encode_with_order`], e.g., inside a little-endian [`CodeqConfig`].
+//!
+//! `NonZero*` integers are encoded like the underlying integer; decoding rejects zero.
+//!
+//! `f32`/`f64` are encoded as their big-endian IEEE-754 bits, `char` as a big-endian `u32`.
+//!
+//! Sequences (`Vec<T>`, `VecDeque<T>`, `Box<[T]>`) are encoded as a `u32` element count followed
+//! by the elements; `[T; N]` omits the count and is decoded in place. Elements go through
+//! [`Encode::encode_slice`] and [`Decode::decode_vec`], so that `u8` is copied as raw bytes and
+//! other fixed-size primitives are staged through a buffer.
+//!
+//! Strings, sequences, maps and sets longer than `u32::MAX` fail to encode with
+//! [`io::ErrorKind::InvalidInput`]; use [`LenPrefixed`] to choose another prefix width.
+//!
+//! `Result<T, E>` and `Bound<T>` are encoded as a `u8` tag followed by the value, like
+//! `Option<T>`; decoding rejects unknown tags. Ranges are encoded as their endpoints.
+//!
+//! `Box<T>`, `Rc<T>`, `Arc<T>` and `Cow` are encoded exactly like the value they refer to.
+//!
+//! `Duration` and `SystemTime` (since `UNIX_EPOCH`) are encoded as `u64` seconds and `u32`
+//! nanoseconds. IP addresses are encoded as octets; `IpAddr` and `SocketAddr` are prefixed with
+//! a `u8` IP version tag, `4` or `6`.
+//!
+//! On Unix, `OsString` and `PathBuf` are encoded as their raw bytes, like `Vec<u8>`.
+//!
+//! Maps and sets are encoded as a `u32` entry count followed by the entries in ascending key
+//! order. `HashMap`/`HashSet` are sorted before encoding, so the same content always yields the
+//! same bytes, and the same checksum. Decoding rejects duplicate keys.
+//!
 //! [`Encode`]: crate::Encode
 //! [`Decode`]: crate::Decode
+//! [`Encode::encode_slice`]: crate::Encode::encode_slice
+//! [`Decode::decode_vec`]: crate::Decode::decode_vec
+//! [`Encode::encode_with_order`]: crate::Encode::encode_with_order
+//! [`LenPrefixed`]: crate::LenPrefixed
+//! [`io::ErrorKind::InvalidInput`]: std::io::ErrorKind::InvalidInput
+//! [`CodeqConfig`]: crate::config::CodeqConfig
+mod array_impl;
 mod bool_impl;
+mod char_impl;
+mod cow_impls;
+mod f32_impl;
+mod f64_impl;
+mod i128_impl;
+mod i16_impl;
+mod i32_impl;
+mod i64_impl;
+mod i8_impl;
+mod map_impls;
+mod net_impls;
+mod nonzero_impls;
 mod option_impls;
+#[cfg(unix)]
+mod os_str_impls;
+mod pointer_impls;
+mod range_impls;
+mod result_impls;
+mod set_impls;
 mod string_impl;
-mod tuple_2_impl;
+mod time_impls;
+mod tuple_impls;
+mod u128_impl;
+mod u16_impl;
 mod u32_impl;
 mod u64_impl;
 mod u8_impl;
 mod unit_impl;
-mod vec_u8_impl;
+mod vec_deque_impl;
+mod vec_impl;
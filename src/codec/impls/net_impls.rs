@@ -0,0 +1,267 @@
+use std::io::Error;
+use std::io::Read;
+use std::io::Write;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::net::SocketAddr;
+use std::net::SocketAddrV4;
+use std::net::SocketAddrV6;
+
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+/// Tag of an IPv4 address in `IpAddr` and `SocketAddr`.
+const TAG_V4: u8 = 4;
+
+/// Tag of an IPv6 address in `IpAddr` and `SocketAddr`.
+const TAG_V6: u8 = 6;
+
+fn invalid_ip_version(tag: u8) -> Error {
+    crate::Error::InvalidTag {
+        type_name: "IP version",
+        tag: tag as u64,
+    }
+    .into()
+}
+
+impl FixedSize for Ipv4Addr {
+    fn encoded_size() -> usize {
+        4
+    }
+}
+
+/// The 4 octets in network order.
+impl Encode for Ipv4Addr {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, w: W) -> Result<usize, Error> {
+        self.octets().encode_with_order(order, w)
+    }
+}
+
+impl Decode for Ipv4Addr {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, r: R) -> Result<Self, Error> {
+        Ok(Ipv4Addr::from(<[u8; 4]>::decode_with_order(order, r)?))
+    }
+}
+
+impl FixedSize for Ipv6Addr {
+    fn encoded_size() -> usize {
+        16
+    }
+}
+
+/// The 16 octets in network order.
+impl Encode for Ipv6Addr {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, w: W) -> Result<usize, Error> {
+        self.octets().encode_with_order(order, w)
+    }
+}
+
+impl Decode for Ipv6Addr {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, r: R) -> Result<Self, Error> {
+        Ok(Ipv6Addr::from(<[u8; 16]>::decode_with_order(order, r)?))
+    }
+}
+
+/// A `u8` IP version tag, `4` or `6`, followed by the address.
+impl Encode for IpAddr {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        match self {
+            IpAddr::V4(a) => Ok(TAG_V4.encode(&mut w)? + a.encode_with_order(order, &mut w)?),
+            IpAddr::V6(a) => Ok(TAG_V6.encode(&mut w)? + a.encode_with_order(order, &mut w)?),
+        }
+    }
+}
+
+impl Decode for IpAddr {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        let tag = u8::decode(&mut r)?;
+        match tag {
+            TAG_V4 => Ok(IpAddr::V4(Ipv4Addr::decode_with_order(order, &mut r)?)),
+            TAG_V6 => Ok(IpAddr::V6(Ipv6Addr::decode_with_order(order, &mut r)?)),
+            _ => Err(invalid_ip_version(tag)),
+        }
+    }
+}
+
+impl FixedSize for SocketAddrV4 {
+    fn encoded_size() -> usize {
+        4 + 2
+    }
+}
+
+/// The address followed by the `u16` port.
+impl Encode for SocketAddrV4 {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        Ok(self.ip().encode_with_order(order, &mut w)?
+            + self.port().encode_with_order(order, &mut w)?)
+    }
+}
+
+impl Decode for SocketAddrV4 {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        let ip = Ipv4Addr::decode_with_order(order, &mut r)?;
+        let port = u16::decode_with_order(order, &mut r)?;
+        Ok(SocketAddrV4::new(ip, port))
+    }
+}
+
+impl FixedSize for SocketAddrV6 {
+    fn encoded_size() -> usize {
+        16 + 2 + 4 + 4
+    }
+}
+
+/// The address, the `u16` port, the `u32` flow info and the `u32` scope id.
+impl Encode for SocketAddrV6 {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        let mut n = 0;
+        n += self.ip().encode_with_order(order, &mut w)?;
+        n += self.port().encode_with_order(order, &mut w)?;
+        n += self.flowinfo().encode_with_order(order, &mut w)?;
+        n += self.scope_id().encode_with_order(order, &mut w)?;
+        Ok(n)
+    }
+}
+
+impl Decode for SocketAddrV6 {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        let ip = Ipv6Addr::decode_with_order(order, &mut r)?;
+        let port = u16::decode_with_order(order, &mut r)?;
+        let flowinfo = u32::decode_with_order(order, &mut r)?;
+        let scope_id = u32::decode_with_order(order, &mut r)?;
+        Ok(SocketAddrV6::new(ip, port, flowinfo, scope_id))
+    }
+}
+
+/// A `u8` IP version tag, `4` or `6`, followed by a `SocketAddrV4` or `SocketAddrV6`.
+impl Encode for SocketAddr {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        match self {
+            SocketAddr::V4(a) => Ok(TAG_V4.encode(&mut w)? + a.encode_with_order(order, &mut w)?),
+            SocketAddr::V6(a) => Ok(TAG_V6.encode(&mut w)? + a.encode_with_order(order, &mut w)?),
+        }
+    }
+}
+
+impl Decode for SocketAddr {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        let tag = u8::decode(&mut r)?;
+        match tag {
+            TAG_V4 => Ok(SocketAddr::V4(SocketAddrV4::decode_with_order(
+                order, &mut r,
+            )?)),
+            TAG_V6 => Ok(SocketAddr::V6(SocketAddrV6::decode_with_order(
+                order, &mut r,
+            )?)),
+            _ => Err(invalid_ip_version(tag)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::net::IpAddr;
+    use std::net::Ipv4Addr;
+    use std::net::Ipv6Addr;
+    use std::net::SocketAddr;
+    use std::net::SocketAddrV6;
+
+    use crate::Decode;
+    use crate::Encode;
+    use crate::FixedSize;
+
+    #[test]
+    fn test_ip_addr_codec() -> Result<(), io::Error> {
+        assert_eq!(4, Ipv4Addr::encoded_size());
+        assert_eq!(16, Ipv6Addr::encoded_size());
+
+        let a = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+        let buf = a.encode_to_vec()?;
+        assert_eq!(vec![4, 127, 0, 0, 1], buf);
+        assert_eq!(a, IpAddr::decode(&mut buf.as_slice())?);
+
+        let a = IpAddr::V6(Ipv6Addr::LOCALHOST);
+        let buf = a.encode_to_vec()?;
+        assert_eq!(vec![6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1], buf);
+        assert_eq!(a, IpAddr::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_socket_addr_codec() -> Result<(), io::Error> {
+        let a: SocketAddr = "10.0.0.1:8080".parse().unwrap();
+        let buf = a.encode_to_vec()?;
+        assert_eq!(vec![4, 10, 0, 0, 1, 0x1f, 0x90], buf);
+        assert_eq!(a, SocketAddr::decode(&mut buf.as_slice())?);
+
+        let a = SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 80, 7, 3));
+        let buf = a.encode_to_vec()?;
+        assert_eq!(1 + 26, buf.len());
+        assert_eq!(a, SocketAddr::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_ip_version() {
+        let err = IpAddr::decode(&mut [5u8, 0, 0, 0, 0].as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!("Invalid IP version tag: 5", err.to_string());
+
+        let err = SocketAddr::decode(&mut [0u8, 0, 0, 0, 0, 0, 0].as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+    }
+}
@@ -0,0 +1,102 @@
+//! `NonZero*` integers are encoded the same as the underlying integer.
+
+use std::io::Error;
+use std::io::Read;
+use std::io::Write;
+use std::num::NonZeroI128;
+use std::num::NonZeroI16;
+use std::num::NonZeroI32;
+use std::num::NonZeroI64;
+use std::num::NonZeroI8;
+use std::num::NonZeroU128;
+use std::num::NonZeroU16;
+use std::num::NonZeroU32;
+use std::num::NonZeroU64;
+use std::num::NonZeroU8;
+
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+macro_rules! impl_nonzero {
+    ($nz:ident, $int:ty) => {
+        impl FixedSize for $nz {
+            fn encoded_size() -> usize {
+                <$int>::encoded_size()
+            }
+        }
+
+        impl Encode for $nz {
+            fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+                self.encode_with_order(ByteOrder::BigEndian, w)
+            }
+
+            fn encode_with_order<W: Write>(&self, order: ByteOrder, w: W) -> Result<usize, Error> {
+                self.get().encode_with_order(order, w)
+            }
+        }
+
+        impl Decode for $nz {
+            fn decode<R: Read>(r: R) -> Result<Self, Error> {
+                Self::decode_with_order(ByteOrder::BigEndian, r)
+            }
+
+            fn decode_with_order<R: Read>(order: ByteOrder, r: R) -> Result<Self, Error> {
+                let v = <$int>::decode_with_order(order, r)?;
+                $nz::new(v).ok_or_else(|| {
+                    crate::Error::InvalidValue {
+                        type_name: stringify!($nz),
+                        value: "0".to_string(),
+                    }
+                    .into()
+                })
+            }
+        }
+    };
+}
+
+impl_nonzero!(NonZeroU8, u8);
+impl_nonzero!(NonZeroU16, u16);
+impl_nonzero!(NonZeroU32, u32);
+impl_nonzero!(NonZeroU64, u64);
+impl_nonzero!(NonZeroU128, u128);
+impl_nonzero!(NonZeroI8, i8);
+impl_nonzero!(NonZeroI16, i16);
+impl_nonzero!(NonZeroI32, i32);
+impl_nonzero!(NonZeroI64, i64);
+impl_nonzero!(NonZeroI128, i128);
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::num::NonZeroI32;
+    use std::num::NonZeroU64;
+    use std::num::NonZeroU8;
+
+    use crate::testing::test_int_coded;
+    use crate::Decode;
+    use crate::Encode;
+
+    #[test]
+    fn test_nonzero_codec() -> anyhow::Result<()> {
+        test_int_coded(NonZeroU8::new(0x12).unwrap())?;
+        test_int_coded(NonZeroU64::new(0x1234567890abcdef).unwrap())?;
+        test_int_coded(NonZeroI32::new(-5).unwrap())?;
+
+        let v = NonZeroU64::new(5).unwrap();
+        assert_eq!(5u64.encode_to_vec()?, v.encode_to_vec()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nonzero_rejects_zero() -> anyhow::Result<()> {
+        let buf = 0u64.encode_to_vec()?;
+        let err = NonZeroU64::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!("Invalid NonZeroU64 value: 0", err.to_string());
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use std::io::Error;
 use std::io::Read;
 use std::io::Write;
 
+use crate::ByteOrder;
 use crate::Decode;
 use crate::Encode;
 use crate::FixedSize;
@@ -13,10 +14,14 @@ impl<T: FixedSize> FixedSize for Option<T> {
 }
 
 impl<T: Encode> Encode for Option<T> {
-    fn encode<W: Write>(&self, mut w: W) -> Result<usize, Error> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
         match self {
             Some(v) => {
-                let n = 1u8.encode(&mut w)? + v.encode(&mut w)?;
+                let n = 1u8.encode(&mut w)? + v.encode_with_order(order, &mut w)?;
                 Ok(n)
             }
             None => 0u8.encode(&mut w),
@@ -25,18 +30,23 @@ impl<T: Encode> Encode for Option<T> {
 }
 
 impl<T: Decode> Decode for Option<T> {
-    fn decode<R: Read>(mut r: R) -> Result<Self, Error> {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
         let tag = u8::decode(&mut r)?;
         match tag {
             0 => Ok(None),
             1 => {
-                let v = T::decode(&mut r)?;
+                let v = T::decode_with_order(order, &mut r)?;
                 Ok(Some(v))
             }
-            _ => Err(Error::new(
-                std::io::ErrorKind::InvalidData,
-                format!("Invalid tag: {}", tag),
-            )),
+            _ => Err(crate::Error::InvalidTag {
+                type_name: "Option",
+                tag: tag as u64,
+            }
+            .into()),
         }
     }
 }
@@ -0,0 +1,118 @@
+//! `OsString` and `PathBuf` are encoded as their raw bytes, in the same layout as `Vec<u8>`.
+//!
+//! Only available on Unix, where an `OsStr` is an arbitrary byte sequence.
+
+use std::ffi::OsStr;
+use std::ffi::OsString;
+use std::io::Error;
+use std::io::Read;
+use std::io::Write;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::ffi::OsStringExt;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+
+impl Encode for &OsStr {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, w: W) -> Result<usize, Error> {
+        self.as_bytes().encode_with_order(order, w)
+    }
+}
+
+impl Encode for OsString {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, w: W) -> Result<usize, Error> {
+        self.as_os_str().encode_with_order(order, w)
+    }
+}
+
+impl Decode for OsString {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, r: R) -> Result<Self, Error> {
+        Ok(OsString::from_vec(Vec::<u8>::decode_with_order(order, r)?))
+    }
+}
+
+impl Encode for &Path {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, w: W) -> Result<usize, Error> {
+        self.as_os_str().encode_with_order(order, w)
+    }
+}
+
+impl Encode for PathBuf {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, w: W) -> Result<usize, Error> {
+        self.as_path().encode_with_order(order, w)
+    }
+}
+
+impl Decode for PathBuf {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, r: R) -> Result<Self, Error> {
+        Ok(PathBuf::from(OsString::decode_with_order(order, r)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+    use std::ffi::OsString;
+    use std::io;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+    use std::path::PathBuf;
+
+    use crate::Decode;
+    use crate::Encode;
+
+    #[test]
+    fn test_os_string_codec() -> Result<(), io::Error> {
+        // Not valid UTF-8
+        let s = OsStr::from_bytes(b"a\xffb").to_os_string();
+
+        let buf = s.encode_to_vec()?;
+        assert_eq!(vec![0, 0, 0, 3, b'a', 0xff, b'b'], buf);
+        assert_eq!(buf, b"a\xffb".to_vec().encode_to_vec()?);
+        assert_eq!(buf, s.as_os_str().encode_to_vec()?);
+
+        assert_eq!(s, OsString::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_path_buf_codec() -> Result<(), io::Error> {
+        let p = PathBuf::from("/var/log");
+
+        let buf = p.encode_to_vec()?;
+        assert_eq!(buf, "/var/log".encode_to_vec()?);
+        assert_eq!(buf, Path::new("/var/log").encode_to_vec()?);
+
+        assert_eq!(p, PathBuf::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+}
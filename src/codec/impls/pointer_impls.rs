@@ -0,0 +1,90 @@
+//! Smart pointers are encoded exactly like the value they point to.
+
+use std::io;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::decode_limits;
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+macro_rules! impl_pointer {
+    ($ptr:ident) => {
+        impl<T: FixedSize> FixedSize for $ptr<T> {
+            fn encoded_size() -> usize {
+                T::encoded_size()
+            }
+        }
+
+        impl<T: Encode> Encode for $ptr<T> {
+            fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+                self.encode_with_order(ByteOrder::BigEndian, w)
+            }
+
+            fn encode_with_order<W: io::Write>(
+                &self,
+                order: ByteOrder,
+                w: W,
+            ) -> Result<usize, io::Error> {
+                (**self).encode_with_order(order, w)
+            }
+
+            fn type_id(&self) -> Option<u32> {
+                (**self).type_id()
+            }
+        }
+
+        impl<T: Decode> Decode for $ptr<T> {
+            fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+                Self::decode_with_order(ByteOrder::BigEndian, r)
+            }
+
+            fn decode_with_order<R: io::Read>(order: ByteOrder, r: R) -> Result<Self, io::Error> {
+                decode_limits::nested(|| Ok($ptr::new(T::decode_with_order(order, r)?)))
+            }
+        }
+    };
+}
+
+impl_pointer!(Box);
+impl_pointer!(Rc);
+impl_pointer!(Arc);
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use crate::Decode;
+    use crate::Encode;
+    use crate::FixedSize;
+
+    #[test]
+    fn test_pointer_codec() -> Result<(), io::Error> {
+        let want = "foo".to_string().encode_to_vec()?;
+
+        let b = Box::new("foo".to_string());
+        assert_eq!(want, b.encode_to_vec()?);
+        assert_eq!(b, Box::<String>::decode(&mut want.as_slice())?);
+
+        let r = Rc::new("foo".to_string());
+        assert_eq!(want, r.encode_to_vec()?);
+        assert_eq!(r, Rc::<String>::decode(&mut want.as_slice())?);
+
+        let a = Arc::new("foo".to_string());
+        assert_eq!(want, a.encode_to_vec()?);
+        assert_eq!(a, Arc::<String>::decode(&mut want.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pointer_fixed_size() {
+        assert_eq!(8, Box::<u64>::encoded_size());
+        assert_eq!(4, Rc::<u32>::encoded_size());
+        assert_eq!(2, Arc::<u16>::encoded_size());
+    }
+}
@@ -0,0 +1,294 @@
+use std::io::Error;
+use std::io::Read;
+use std::io::Write;
+use std::ops::Bound;
+use std::ops::Range;
+use std::ops::RangeFrom;
+use std::ops::RangeFull;
+use std::ops::RangeInclusive;
+use std::ops::RangeTo;
+use std::ops::RangeToInclusive;
+
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+/// A `u8` tag, `0` for `Included`, `1` for `Excluded` and `2` for `Unbounded`, followed by the
+/// endpoint if there is one.
+impl<T: Encode> Encode for Bound<T> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        match self {
+            Bound::Included(v) => Ok(0u8.encode(&mut w)? + v.encode_with_order(order, &mut w)?),
+            Bound::Excluded(v) => Ok(1u8.encode(&mut w)? + v.encode_with_order(order, &mut w)?),
+            Bound::Unbounded => 2u8.encode(&mut w),
+        }
+    }
+}
+
+impl<T: Decode> Decode for Bound<T> {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        let tag = u8::decode(&mut r)?;
+        match tag {
+            0 => Ok(Bound::Included(T::decode_with_order(order, &mut r)?)),
+            1 => Ok(Bound::Excluded(T::decode_with_order(order, &mut r)?)),
+            2 => Ok(Bound::Unbounded),
+            _ => Err(crate::Error::InvalidTag {
+                type_name: "Bound",
+                tag: tag as u64,
+            }
+            .into()),
+        }
+    }
+}
+
+impl<T: FixedSize> FixedSize for Range<T> {
+    fn encoded_size() -> usize {
+        T::encoded_size() * 2
+    }
+}
+
+/// `start` followed by `end`.
+impl<T: Encode> Encode for Range<T> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        Ok(self.start.encode_with_order(order, &mut w)?
+            + self.end.encode_with_order(order, &mut w)?)
+    }
+}
+
+impl<T: Decode> Decode for Range<T> {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        let start = T::decode_with_order(order, &mut r)?;
+        let end = T::decode_with_order(order, &mut r)?;
+        Ok(start..end)
+    }
+}
+
+impl<T: FixedSize> FixedSize for RangeInclusive<T> {
+    fn encoded_size() -> usize {
+        T::encoded_size() * 2
+    }
+}
+
+/// `start` followed by `end`.
+///
+/// Whether the range has been exhausted by iteration is not encoded.
+impl<T: Encode> Encode for RangeInclusive<T> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        Ok(self.start().encode_with_order(order, &mut w)?
+            + self.end().encode_with_order(order, &mut w)?)
+    }
+}
+
+impl<T: Decode> Decode for RangeInclusive<T> {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        let start = T::decode_with_order(order, &mut r)?;
+        let end = T::decode_with_order(order, &mut r)?;
+        Ok(start..=end)
+    }
+}
+
+impl<T: FixedSize> FixedSize for RangeFrom<T> {
+    fn encoded_size() -> usize {
+        T::encoded_size()
+    }
+}
+
+impl<T: Encode> Encode for RangeFrom<T> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, w: W) -> Result<usize, Error> {
+        self.start.encode_with_order(order, w)
+    }
+}
+
+impl<T: Decode> Decode for RangeFrom<T> {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, r: R) -> Result<Self, Error> {
+        Ok(T::decode_with_order(order, r)?..)
+    }
+}
+
+impl<T: FixedSize> FixedSize for RangeTo<T> {
+    fn encoded_size() -> usize {
+        T::encoded_size()
+    }
+}
+
+impl<T: Encode> Encode for RangeTo<T> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, w: W) -> Result<usize, Error> {
+        self.end.encode_with_order(order, w)
+    }
+}
+
+impl<T: Decode> Decode for RangeTo<T> {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, r: R) -> Result<Self, Error> {
+        Ok(..T::decode_with_order(order, r)?)
+    }
+}
+
+impl<T: FixedSize> FixedSize for RangeToInclusive<T> {
+    fn encoded_size() -> usize {
+        T::encoded_size()
+    }
+}
+
+impl<T: Encode> Encode for RangeToInclusive<T> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, w: W) -> Result<usize, Error> {
+        self.end.encode_with_order(order, w)
+    }
+}
+
+impl<T: Decode> Decode for RangeToInclusive<T> {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, r: R) -> Result<Self, Error> {
+        Ok(..=T::decode_with_order(order, r)?)
+    }
+}
+
+impl FixedSize for RangeFull {
+    fn encoded_size() -> usize {
+        0
+    }
+}
+
+impl Encode for RangeFull {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, _order: ByteOrder, _w: W) -> Result<usize, Error> {
+        Ok(0)
+    }
+}
+
+impl Decode for RangeFull {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(_order: ByteOrder, _r: R) -> Result<Self, Error> {
+        Ok(..)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::ops::Bound;
+    use std::ops::RangeFrom;
+    use std::ops::RangeFull;
+    use std::ops::RangeTo;
+    use std::ops::RangeToInclusive;
+
+    use crate::Decode;
+    use crate::Encode;
+    use crate::FixedSize;
+
+    #[test]
+    fn test_bound_codec() -> Result<(), io::Error> {
+        let cases = [
+            (Bound::Included(5u16), vec![0, 0, 5]),
+            (Bound::Excluded(5u16), vec![1, 0, 5]),
+            (Bound::Unbounded, vec![2]),
+        ];
+
+        for (b, want) in cases {
+            let buf = b.encode_to_vec()?;
+            assert_eq!(want, buf);
+            assert_eq!(b, Bound::<u16>::decode(&mut buf.as_slice())?);
+        }
+
+        let err = Bound::<u16>::decode(&mut [3u8].as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!("Invalid Bound tag: 3", err.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_codec() -> Result<(), io::Error> {
+        assert_eq!(16, <std::ops::Range<u64>>::encoded_size());
+        assert_eq!(16, <std::ops::RangeInclusive<u64>>::encoded_size());
+
+        let r = 1u64..5;
+        let buf = r.encode_to_vec()?;
+        assert_eq!(vec![0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 5], buf);
+        assert_eq!(r, <std::ops::Range<u64>>::decode(&mut buf.as_slice())?);
+
+        let r = 1u64..=5;
+        let buf = r.encode_to_vec()?;
+        assert_eq!(vec![0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 5], buf);
+        assert_eq!(
+            r,
+            <std::ops::RangeInclusive<u64>>::decode(&mut buf.as_slice())?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_half_open_range_codec() -> Result<(), io::Error> {
+        let buf = (3u8..).encode_to_vec()?;
+        assert_eq!(vec![3], buf);
+        assert_eq!(3u8.., RangeFrom::<u8>::decode(&mut buf.as_slice())?);
+
+        let buf = (..3u8).encode_to_vec()?;
+        assert_eq!(vec![3], buf);
+        assert_eq!(..3u8, RangeTo::<u8>::decode(&mut buf.as_slice())?);
+
+        let buf = (..=3u8).encode_to_vec()?;
+        assert_eq!(vec![3], buf);
+        assert_eq!(..=3u8, RangeToInclusive::<u8>::decode(&mut buf.as_slice())?);
+
+        let buf = (..).encode_to_vec()?;
+        assert!(buf.is_empty());
+        assert_eq!(.., RangeFull::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use std::io::Error;
+use std::io::Read;
+use std::io::Write;
+
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+
+/// A `u8` tag, `0` for `Ok` and `1` for `Err`, followed by the value.
+impl<T: Encode, E: Encode> Encode for Result<T, E> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        match self {
+            Ok(v) => Ok(0u8.encode(&mut w)? + v.encode_with_order(order, &mut w)?),
+            Err(e) => Ok(1u8.encode(&mut w)? + e.encode_with_order(order, &mut w)?),
+        }
+    }
+}
+
+impl<T: Decode, E: Decode> Decode for Result<T, E> {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        let tag = u8::decode(&mut r)?;
+        match tag {
+            0 => Ok(Ok(T::decode_with_order(order, &mut r)?)),
+            1 => Ok(Err(E::decode_with_order(order, &mut r)?)),
+            _ => Err(crate::Error::InvalidTag {
+                type_name: "Result",
+                tag: tag as u64,
+            }
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::Decode;
+    use crate::Encode;
+
+    #[test]
+    fn test_result_codec() -> Result<(), io::Error> {
+        {
+            let a = Ok::<u32, String>(5);
+
+            let b = a.encode_to_vec()?;
+            assert_eq!(b, vec![0, 0, 0, 0, 5]);
+
+            let c = Result::<u32, String>::decode(&mut b.as_slice())?;
+            assert_eq!(c, a);
+        }
+
+        {
+            let a = Err::<u32, String>("foo".to_string());
+
+            let b = a.encode_to_vec()?;
+            assert_eq!(b, vec![1, 0, 0, 0, 3, 102, 111, 111]);
+
+            let c = Result::<u32, String>::decode(&mut b.as_slice())?;
+            assert_eq!(c, a);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_result_invalid_tag() {
+        let err = Result::<u8, u8>::decode(&mut [2u8, 0].as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!("Invalid Result tag: 2", err.to_string());
+        assert_eq!(
+            Some(&crate::Error::InvalidTag {
+                type_name: "Result",
+                tag: 2
+            }),
+            crate::Error::from_io(&err)
+        );
+    }
+}
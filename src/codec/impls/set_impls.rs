@@ -0,0 +1,154 @@
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+use std::io::Error;
+use std::io::Read;
+use std::io::Write;
+
+use crate::decode_limits;
+use crate::error_context_ext::ErrorContextExt;
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::LenPrefix;
+
+/// A `u32` element count followed by the elements in ascending order.
+impl<T: Encode> Encode for BTreeSet<T> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        let mut n = u32::encode_len(self.len(), order, &mut w)?;
+        for v in self {
+            n += v.encode_with_order(order, &mut w)?;
+        }
+        Ok(n)
+    }
+}
+
+impl<T: Decode + Ord> Decode for BTreeSet<T> {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        decode_limits::nested(|| {
+            let len = u32::decode_len(order, &mut r).in_path("len")?;
+            decode_limits::reserve::<T>(len)?;
+
+            let mut s = BTreeSet::new();
+            for i in 0..len {
+                let item = T::decode_with_order(order, &mut r)
+                    .map_err(|e| e.in_path(format!("[{}]", i)))?;
+                if !s.insert(item) {
+                    return Err(duplicate_element().in_path(format!("[{}]", i)));
+                }
+            }
+            Ok(s)
+        })
+    }
+}
+
+/// Encoded the same as a `BTreeSet` with the same content: elements are sorted, so that the
+/// output does not depend on the hasher state.
+impl<T: Encode + Ord, S> Encode for HashSet<T, S> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        let mut elements = self.iter().collect::<Vec<_>>();
+        elements.sort_unstable();
+
+        let mut n = u32::encode_len(elements.len(), order, &mut w)?;
+        for v in elements {
+            n += v.encode_with_order(order, &mut w)?;
+        }
+        Ok(n)
+    }
+}
+
+impl<T, S> Decode for HashSet<T, S>
+where
+    T: Decode + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        decode_limits::nested(|| {
+            let len = u32::decode_len(order, &mut r).in_path("len")?;
+            decode_limits::reserve::<T>(len)?;
+
+            let mut s = HashSet::with_hasher(S::default());
+            for i in 0..len {
+                let item = T::decode_with_order(order, &mut r)
+                    .map_err(|e| e.in_path(format!("[{}]", i)))?;
+                if !s.insert(item) {
+                    return Err(duplicate_element().in_path(format!("[{}]", i)));
+                }
+            }
+            Ok(s)
+        })
+    }
+}
+
+fn duplicate_element() -> Error {
+    crate::Error::DuplicateSetElement.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::collections::HashSet;
+    use std::io;
+
+    use crate::Decode;
+    use crate::Encode;
+    use crate::LocatedError;
+
+    #[test]
+    fn test_btree_set_codec() -> Result<(), io::Error> {
+        let s = BTreeSet::from([3u16, 1, 2]);
+
+        let buf = s.encode_to_vec()?;
+        assert_eq!(vec![0, 0, 0, 3, 0, 1, 0, 2, 0, 3], buf);
+        assert_eq!(s, BTreeSet::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_set_codec_is_sorted() -> Result<(), io::Error> {
+        let h = (0..100u64).rev().collect::<HashSet<_>>();
+        let b = (0..100u64).collect::<BTreeSet<_>>();
+
+        let buf = h.encode_to_vec()?;
+        assert_eq!(b.encode_to_vec()?, buf);
+        assert_eq!(h, HashSet::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_decode_duplicate_element() -> Result<(), io::Error> {
+        let buf = vec![0, 0, 0, 2, 1, 1];
+
+        let err = BTreeSet::<u8>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+        let err = HashSet::<u8>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!("Duplicate set element; at:([1])", err.to_string());
+
+        let buf = vec![0, 0, 0, 2, 0, 9];
+        let err = BTreeSet::<bool>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!("[1]", LocatedError::from_io(&err).unwrap().path());
+
+        Ok(())
+    }
+}
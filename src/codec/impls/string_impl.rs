@@ -1,16 +1,23 @@
 use std::io;
 
-use byteorder::BigEndian;
-use byteorder::ReadBytesExt;
-use byteorder::WriteBytesExt;
-
+use crate::error_context_ext::ErrorContextExt;
+use crate::ByteOrder;
 use crate::Decode;
 use crate::Encode;
+use crate::LenPrefix;
 
 impl Encode for &str {
-    fn encode<W: io::Write>(&self, mut w: W) -> Result<usize, io::Error> {
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(
+        &self,
+        order: ByteOrder,
+        mut w: W,
+    ) -> Result<usize, io::Error> {
         let bytes = self.as_bytes();
-        w.write_u32::<BigEndian>(bytes.len() as u32)?;
+        u32::encode_len(bytes.len(), order, &mut w)?;
         w.write_all(bytes)?;
         Ok(bytes.len() + 4)
     }
@@ -18,16 +25,23 @@ impl Encode for &str {
 
 impl Encode for String {
     fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
-        self.as_str().encode(w)
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(&self, order: ByteOrder, w: W) -> Result<usize, io::Error> {
+        self.as_str().encode_with_order(order, w)
     }
 }
 
 impl Decode for String {
-    fn decode<R: io::Read>(mut r: R) -> Result<Self, io::Error> {
-        let len = r.read_u32::<BigEndian>()? as usize;
-        let mut buf = vec![0; len];
-        r.read_exact(&mut buf)?;
-        String::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: io::Read>(order: ByteOrder, mut r: R) -> Result<Self, io::Error> {
+        let len = u32::decode_len(order, &mut r).in_path("len")?;
+        let buf = u8::decode_vec(len, order, r)?;
+        Ok(String::from_utf8(buf).map_err(crate::Error::InvalidUtf8)?)
     }
 }
 
@@ -38,6 +52,7 @@ mod tests {
 
     use crate::Decode;
     use crate::Encode;
+    use crate::Error;
 
     #[test]
     fn test_string_codec() -> Result<(), io::Error> {
@@ -97,6 +112,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_string_unexpected_eof() -> anyhow::Result<()> {
+        // Truncated length
+        let err = String::decode(&[0, 0, 1][..]).unwrap_err();
+        assert_eq!(
+            Some(&Error::UnexpectedEof {
+                read: 3,
+                expected: 4
+            }),
+            Error::from_io(&err)
+        );
+
+        // Truncated content
+        let err = String::decode(&[0, 0, 0, 3, b'a'][..]).unwrap_err();
+        assert_eq!(
+            Some(&Error::UnexpectedEof {
+                read: 1,
+                expected: 3
+            }),
+            Error::from_io(&err)
+        );
+
+        Ok(())
+    }
+
     /// Encodes `s`, asserting the exact wire bytes, the returned length, and a
     /// clean round-trip back through `String::decode`.
     fn check_str_encode(s: &str, expect: &[u8]) -> Result<(), io::Error> {
@@ -0,0 +1,169 @@
+use std::io::Error;
+use std::io::Read;
+use std::io::Write;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+impl FixedSize for Duration {
+    fn encoded_size() -> usize {
+        8 + 4
+    }
+}
+
+/// Whole seconds as a `u64` followed by the sub-second nanoseconds as a `u32`.
+impl Encode for Duration {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, Error> {
+        let mut n = 0;
+        n += self.as_secs().encode_with_order(order, &mut w)?;
+        n += self.subsec_nanos().encode_with_order(order, &mut w)?;
+        Ok(n)
+    }
+}
+
+impl Decode for Duration {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        let secs = u64::decode_with_order(order, &mut r)?;
+        let nanos = u32::decode_with_order(order, &mut r)?;
+
+        if nanos >= NANOS_PER_SEC {
+            return Err(crate::Error::InvalidValue {
+                type_name: "Duration",
+                value: format!("{} nanoseconds", nanos),
+            }
+            .into());
+        }
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+impl FixedSize for SystemTime {
+    fn encoded_size() -> usize {
+        Duration::encoded_size()
+    }
+}
+
+/// The [`Duration`] since [`UNIX_EPOCH`].
+///
+/// A time before the epoch can not be encoded and returns an
+/// [`std::io::ErrorKind::InvalidInput`] error.
+impl Encode for SystemTime {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, w: W) -> Result<usize, Error> {
+        let since_epoch =
+            self.duration_since(UNIX_EPOCH).map_err(|e| crate::Error::TimeBeforeEpoch {
+                before: e.duration(),
+            })?;
+        since_epoch.encode_with_order(order, w)
+    }
+}
+
+impl Decode for SystemTime {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, r: R) -> Result<Self, Error> {
+        let since_epoch = Duration::decode_with_order(order, r)?;
+        UNIX_EPOCH.checked_add(since_epoch).ok_or_else(|| {
+            crate::Error::InvalidValue {
+                type_name: "SystemTime",
+                value: format!("{:?} since UNIX_EPOCH", since_epoch),
+            }
+            .into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::time::Duration;
+    use std::time::SystemTime;
+    use std::time::UNIX_EPOCH;
+
+    use crate::Decode;
+    use crate::Encode;
+    use crate::FixedSize;
+
+    #[test]
+    fn test_duration_codec() -> Result<(), io::Error> {
+        assert_eq!(12, Duration::encoded_size());
+
+        let d = Duration::new(5, 7);
+        let buf = d.encode_to_vec()?;
+        assert_eq!(vec![0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 7], buf);
+        assert_eq!(d, Duration::decode(&mut buf.as_slice())?);
+
+        let d = Duration::MAX;
+        let buf = d.encode_to_vec()?;
+        assert_eq!(d, Duration::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_duration_invalid_nanos() -> Result<(), io::Error> {
+        let mut buf = 5u64.encode_to_vec()?;
+        1_000_000_000u32.encode(&mut buf)?;
+
+        let err = Duration::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!(
+            "Invalid Duration value: 1000000000 nanoseconds",
+            err.to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_system_time_codec() -> Result<(), io::Error> {
+        assert_eq!(12, SystemTime::encoded_size());
+
+        let t = UNIX_EPOCH + Duration::new(1_700_000_000, 123);
+        let buf = t.encode_to_vec()?;
+        assert_eq!(Duration::new(1_700_000_000, 123).encode_to_vec()?, buf);
+        assert_eq!(t, SystemTime::decode(&mut buf.as_slice())?);
+
+        let now = SystemTime::now();
+        let buf = now.encode_to_vec()?;
+        assert_eq!(now, SystemTime::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_system_time_before_epoch() {
+        let t = UNIX_EPOCH - Duration::from_secs(1);
+        let err = t.encode_to_vec().unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidInput, err.kind());
+    }
+
+    #[test]
+    fn test_system_time_out_of_range() -> Result<(), io::Error> {
+        let buf = Duration::MAX.encode_to_vec()?;
+        let err = SystemTime::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+        Ok(())
+    }
+}
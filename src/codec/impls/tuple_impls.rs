@@ -0,0 +1,124 @@
+use std::io;
+
+use crate::error_context_ext::ErrorContextExt;
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+/// Implements the codec traits for a tuple, encoding elements in order without any separator.
+macro_rules! impl_tuple {
+    ($($t:ident . $i:tt),+) => {
+        impl<$($t: FixedSize),+> FixedSize for ($($t,)+) {
+            fn encoded_size() -> usize {
+                0 $(+ $t::encoded_size())+
+            }
+        }
+
+        impl<$($t: Encode),+> Encode for ($($t,)+) {
+            fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+                self.encode_with_order(ByteOrder::BigEndian, w)
+            }
+
+            fn encode_with_order<W: io::Write>(
+                &self,
+                order: ByteOrder,
+                mut w: W,
+            ) -> Result<usize, io::Error> {
+                let mut n = 0;
+                $(n += self.$i.encode_with_order(order, &mut w)?;)+
+                Ok(n)
+            }
+        }
+
+        impl<$($t: Decode),+> Decode for ($($t,)+) {
+            fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+                Self::decode_with_order(ByteOrder::BigEndian, r)
+            }
+
+            fn decode_with_order<R: io::Read>(
+                order: ByteOrder,
+                mut r: R,
+            ) -> Result<Self, io::Error> {
+                Ok(($($t::decode_with_order(order, &mut r).in_path(stringify!($i))?,)+))
+            }
+        }
+    };
+}
+
+impl_tuple!(A.0);
+impl_tuple!(A.0, B.1);
+impl_tuple!(A.0, B.1, C.2);
+impl_tuple!(A.0, B.1, C.2, D.3);
+impl_tuple!(A.0, B.1, C.2, D.3, E.4);
+impl_tuple!(A.0, B.1, C.2, D.3, E.4, F.5);
+impl_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6);
+impl_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7);
+impl_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8);
+impl_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8, J.9);
+impl_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8, J.9, K.10);
+impl_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8, J.9, K.10, L.11);
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::Decode;
+    use crate::Encode;
+    use crate::FixedSize;
+
+    #[test]
+    fn test_tuple_2_codec() -> Result<(), io::Error> {
+        let v = (1u64, 2u32);
+        let mut buf = Vec::new();
+        let n = v.encode(&mut buf)?;
+        assert_eq!(n, buf.len());
+        assert_eq!(buf.len(), 8 + 4);
+
+        let b = <(u64, u32)>::decode(&mut buf.as_slice())?;
+        assert_eq!(v, b);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tuple_1_codec() -> Result<(), io::Error> {
+        assert_eq!(4, <(u32,)>::encoded_size());
+
+        let buf = (5u32,).encode_to_vec()?;
+        assert_eq!(vec![0, 0, 0, 5], buf);
+        assert_eq!((5u32,), <(u32,)>::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tuple_3_codec() -> Result<(), io::Error> {
+        let v = (1u64, 2u32, "a".to_string());
+        let buf = v.encode_to_vec()?;
+        assert_eq!(
+            vec![
+                0, 0, 0, 0, 0, 0, 0, 1, //
+                0, 0, 0, 2, //
+                0, 0, 0, 1, b'a', //
+            ],
+            buf
+        );
+        assert_eq!(v, <(u64, u32, String)>::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tuple_12_codec() -> Result<(), io::Error> {
+        type T12 = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u16);
+        assert_eq!(13, T12::encoded_size());
+
+        let v: T12 = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+        let buf = v.encode_to_vec()?;
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 0, 12], buf);
+        assert_eq!(v, T12::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
 use std::io;
 use std::mem::size_of;
 
-use byteorder::ReadBytesExt;
+use byteorder::ByteOrder as _;
 use byteorder::WriteBytesExt;
 
+use crate::byte_order::with_byte_order;
+use crate::codec::bulk;
+use crate::ByteOrder;
 use crate::Decode;
 use crate::Encode;
 use crate::FixedSize;
@@ -15,26 +18,79 @@ impl FixedSize for u32 {
 }
 
 impl Encode for u32 {
-    fn encode<W: io::Write>(&self, mut w: W) -> Result<usize, io::Error> {
-        w.write_u32::<byteorder::BigEndian>(*self)?;
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(
+        &self,
+        order: ByteOrder,
+        mut w: W,
+    ) -> Result<usize, io::Error> {
+        with_byte_order!(order, O => w.write_u32::<O>(*self))?;
         Ok(Self::encoded_size())
     }
+
+    fn encode_slice<W: io::Write>(
+        items: &[Self],
+        order: ByteOrder,
+        w: W,
+    ) -> Result<usize, io::Error> {
+        bulk::encode_fixed_size_slice(items, order, w)
+    }
 }
 
 impl Decode for u32 {
-    fn decode<R: io::Read>(mut r: R) -> Result<Self, io::Error> {
-        let v = r.read_u32::<byteorder::BigEndian>()?;
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: io::Read>(order: ByteOrder, mut r: R) -> Result<Self, io::Error> {
+        let mut buf = [0; size_of::<Self>()];
+        bulk::read_exact(&mut r, &mut buf)?;
+        let v = with_byte_order!(order, O => O::read_u32(&buf));
         Ok(v)
     }
+
+    fn decode_vec<R: io::Read>(len: usize, order: ByteOrder, r: R) -> Result<Vec<Self>, io::Error> {
+        bulk::decode_fixed_size_vec(len, order, r)
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use crate::testing::test_int_coded;
+    use crate::Decode;
+    use crate::Error;
 
     #[test]
     fn test_u32_codec() -> anyhow::Result<()> {
         test_int_coded(0x12345678u32)
     }
+
+    #[test]
+    fn test_u32_unexpected_eof() -> anyhow::Result<()> {
+        let err = u32::decode(&[1, 2, 3][..]).unwrap_err();
+        assert_eq!(
+            Some(&Error::UnexpectedEof {
+                read: 3,
+                expected: 4
+            }),
+            Error::from_io(&err)
+        );
+
+        // 2 of 3 elements and half of the third
+        let b = [0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0];
+        let err = Vec::<u32>::decode(&b[..]).unwrap_err();
+        assert_eq!(
+            Some(&Error::UnexpectedEof {
+                read: 10,
+                expected: 12
+            }),
+            Error::from_io(&err)
+        );
+
+        Ok(())
+    }
 }
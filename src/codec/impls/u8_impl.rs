@@ -1,9 +1,13 @@
 use std::io;
+use std::io::Read;
 use std::mem::size_of;
 
-use byteorder::ReadBytesExt;
 use byteorder::WriteBytesExt;
 
+use crate::codec::bulk;
+use crate::decode_limits;
+use crate::error_context_ext::ErrorContextExt;
+use crate::ByteOrder;
 use crate::Decode;
 use crate::Encode;
 use crate::FixedSize;
@@ -19,13 +23,53 @@ impl Encode for u8 {
         w.write_u8(*self)?;
         Ok(Self::encoded_size())
     }
+
+    /// A single byte, the same in either byte order.
+    fn encode_with_order<W: io::Write>(&self, _order: ByteOrder, w: W) -> Result<usize, io::Error> {
+        self.encode(w)
+    }
+
+    fn encode_slice<W: io::Write>(
+        items: &[Self],
+        _order: ByteOrder,
+        mut w: W,
+    ) -> Result<usize, io::Error> {
+        w.write_all(items)?;
+        Ok(items.len())
+    }
 }
 
 impl Decode for u8 {
-    fn decode<R: io::Read>(mut r: R) -> Result<Self, io::Error> {
-        let v = r.read_u8()?;
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        let mut buf = [0; 1];
+        bulk::read_exact(r, &mut buf)?;
+        let v = buf[0];
         Ok(v)
     }
+
+    fn decode_with_order<R: io::Read>(_order: ByteOrder, r: R) -> Result<Self, io::Error> {
+        Self::decode(r)
+    }
+
+    fn decode_vec<R: io::Read>(
+        len: usize,
+        _order: ByteOrder,
+        r: R,
+    ) -> Result<Vec<Self>, io::Error> {
+        decode_limits::reserve::<Self>(len)?;
+
+        let mut buf = Vec::with_capacity(decode_limits::initial_capacity::<Self>(len));
+        r.take(len as u64).read_to_end(&mut buf)?;
+        if buf.len() < len {
+            let err: io::Error = crate::Error::UnexpectedEof {
+                read: buf.len() as u64,
+                expected: len as u64,
+            }
+            .into();
+            return Err(err.in_path(format!("[{}]", buf.len())));
+        }
+        Ok(buf)
+    }
 }
 
 #[cfg(test)]
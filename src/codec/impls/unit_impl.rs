@@ -1,5 +1,6 @@
 use std::io;
 
+use crate::ByteOrder;
 use crate::Decode;
 use crate::Encode;
 use crate::FixedSize;
@@ -14,12 +15,21 @@ impl Encode for () {
     fn encode<W: io::Write>(&self, _w: W) -> Result<usize, io::Error> {
         Ok(0)
     }
+
+    /// Nothing, in either byte order.
+    fn encode_with_order<W: io::Write>(&self, _order: ByteOrder, w: W) -> Result<usize, io::Error> {
+        self.encode(w)
+    }
 }
 
 impl Decode for () {
     fn decode<R: io::Read>(_r: R) -> Result<Self, io::Error> {
         Ok(())
     }
+
+    fn decode_with_order<R: io::Read>(_order: ByteOrder, r: R) -> Result<Self, io::Error> {
+        Self::decode(r)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,65 @@
+use std::collections::VecDeque;
+use std::io;
+
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::LenPrefix;
+
+/// Encoded the same as `Vec<T>`: a `u32` element count followed by the elements.
+impl<T: Encode> Encode for VecDeque<T> {
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(
+        &self,
+        order: ByteOrder,
+        mut w: W,
+    ) -> Result<usize, io::Error> {
+        let (a, b) = self.as_slices();
+
+        let mut n = u32::encode_len(self.len(), order, &mut w)?;
+        n += T::encode_slice(a, order, &mut w)?;
+        n += T::encode_slice(b, order, &mut w)?;
+        Ok(n)
+    }
+}
+
+impl<T: Decode> Decode for VecDeque<T> {
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: io::Read>(order: ByteOrder, r: R) -> Result<Self, io::Error> {
+        Ok(Vec::<T>::decode_with_order(order, r)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::io;
+
+    use crate::Decode;
+    use crate::Encode;
+
+    #[test]
+    fn test_vec_deque_codec() -> Result<(), io::Error> {
+        // Make the ring buffer wrap around so that it has two slices.
+        let mut v = VecDeque::with_capacity(4);
+        v.extend([0u8, 0, 1, 2]);
+        v.pop_front();
+        v.pop_front();
+        v.push_back(3);
+        v.push_back(4);
+
+        let buf = v.encode_to_vec()?;
+        assert_eq!(vec![0, 0, 0, 4, 1, 2, 3, 4], buf);
+        assert_eq!(buf, vec![1u8, 2, 3, 4].encode_to_vec()?);
+
+        assert_eq!(v, VecDeque::<u8>::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+use std::io;
+use std::io::Error;
+use std::io::Read;
+
+use crate::decode_limits;
+use crate::error_context_ext::ErrorContextExt;
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::LenPrefix;
+
+/// A `u32` element count followed by the elements.
+///
+/// For `&[u8]` this is a `u32` byte length followed by the raw bytes.
+impl<T: Encode> Encode for &[T] {
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(
+        &self,
+        order: ByteOrder,
+        mut w: W,
+    ) -> Result<usize, io::Error> {
+        let mut n = u32::encode_len(self.len(), order, &mut w)?;
+        n += T::encode_slice(self, order, &mut w)?;
+        Ok(n)
+    }
+}
+
+/// Encoded the same as `&[T]`.
+impl<T: Encode> Encode for Vec<T> {
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(&self, order: ByteOrder, w: W) -> Result<usize, io::Error> {
+        self.as_slice().encode_with_order(order, w)
+    }
+}
+
+impl<T: Decode> Decode for Vec<T> {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, Error> {
+        decode_limits::nested(|| {
+            let len = u32::decode_len(order, &mut r).in_path("len")?;
+            T::decode_vec(len, order, r)
+        })
+    }
+}
+
+/// Encoded the same as `&[T]`.
+impl<T: Encode> Encode for Box<[T]> {
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(&self, order: ByteOrder, w: W) -> Result<usize, io::Error> {
+        (&**self).encode_with_order(order, w)
+    }
+}
+
+impl<T: Decode> Decode for Box<[T]> {
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, r: R) -> Result<Self, Error> {
+        Ok(Vec::<T>::decode_with_order(order, r)?.into_boxed_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io;
+
+    use crate::Decode;
+    use crate::Encode;
+    use crate::LocatedError;
+
+    #[test]
+    fn test_vec_u8_codec() -> Result<(), io::Error> {
+        let v = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let mut buf = Vec::new();
+        let n = v.encode(&mut buf)?;
+        assert_eq!(n, buf.len());
+        assert_eq!(buf.len(), 4 + v.len());
+
+        let b = Vec::<u8>::decode(&mut buf.as_slice())?;
+        assert_eq!(v, b);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vec_u8_wire_format() -> Result<(), io::Error> {
+        let v = vec![1u8, 2, 3];
+        assert_eq!(vec![0, 0, 0, 3, 1, 2, 3], v.encode_to_vec()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_vec_fixed_size_codec() -> Result<(), io::Error> {
+        let v = vec![1u32, 2, 0x01020304];
+        let buf = v.encode_to_vec()?;
+        assert_eq!(
+            vec![
+                0, 0, 0, 3, // count
+                0, 0, 0, 1, //
+                0, 0, 0, 2, //
+                1, 2, 3, 4, //
+            ],
+            buf
+        );
+        assert_eq!(v, Vec::<u32>::decode(&mut buf.as_slice())?);
+
+        // Larger than a bulk chunk
+        let v = (0..100_000u64).collect::<Vec<_>>();
+        let buf = v.encode_to_vec()?;
+        assert_eq!(4 + 8 * 100_000, buf.len());
+        assert_eq!(v, Vec::<u64>::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vec_variable_size_codec() -> Result<(), io::Error> {
+        let v = vec!["a".to_string(), "bc".to_string()];
+        let buf = v.encode_to_vec()?;
+        assert_eq!(
+            vec![
+                0, 0, 0, 2, // count
+                0, 0, 0, 1, b'a', //
+                0, 0, 0, 2, b'b', b'c', //
+            ],
+            buf
+        );
+        assert_eq!(v, Vec::<String>::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vec_decode_invalid_element() -> Result<(), io::Error> {
+        let buf = [0, 0, 0, 2, 1, 2];
+        let err = Vec::<bool>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!("[1]", LocatedError::from_io(&err).unwrap().path());
+        Ok(())
+    }
+
+    #[test]
+    fn test_vec_decode_truncated_element() -> Result<(), io::Error> {
+        // The second of 3 elements is incomplete
+        let buf = [0, 0, 0, 3, 0, 0, 0, 1, 0, 0];
+        let err = Vec::<u32>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(
+            "Unexpected EOF after 6 of 12 bytes; at:([1])",
+            err.to_string()
+        );
+
+        let buf = [0, 0, 0, 3, 1];
+        let err = Vec::<u8>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!("[1]", LocatedError::from_io(&err).unwrap().path());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_boxed_slice_codec() -> Result<(), io::Error> {
+        let v: Box<[u16]> = vec![1u16, 2].into_boxed_slice();
+        let buf = v.encode_to_vec()?;
+        assert_eq!(vec![0, 0, 0, 2, 0, 1, 0, 2], buf);
+        assert_eq!(v, Box::<[u16]>::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_slice_encode() -> Result<(), io::Error> {
+        let s: &[u8] = b"abc";
+        let buf = s.encode_to_vec()?;
+        assert_eq!(vec![0, 0, 0, 3, b'a', b'b', b'c'], buf);
+        assert_eq!(buf, s.to_vec().encode_to_vec()?);
+
+        let s: &[u16] = &[1, 2];
+        assert_eq!(vec![0, 0, 0, 2, 0, 1, 0, 2], s.encode_to_vec()?);
+
+        Ok(())
+    }
+}
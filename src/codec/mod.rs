@@ -1,3 +1,4 @@
+mod bulk;
 mod decode;
 mod encode;
 mod impls;
@@ -0,0 +1,448 @@
+use std::io;
+use std::marker::PhantomData;
+
+use crate::config::Checksum;
+use crate::config::CodeqConfig;
+use crate::config::UnkeyedChecksum;
+use crate::ByteOrder;
+use crate::ChecksumOptions;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
+
+/// A config whose checksum is stored with the ID of its algorithm, so that the algorithm of
+/// existing data is found when reading it, rather than fixed by the type.
+///
+/// Data is written with the algorithm of `C`, and data written with any [`DynamicAlgorithm`]
+/// enabled by the crate features is read back, whichever `C` the reader uses. Storage can thus
+/// move from one algorithm to another, e.g., from `Dynamic<Crc32fast>` to
+/// `Dynamic<Crc64fastNvme>`, while existing data is still readable.
+///
+/// The checksum takes 9 bytes: the algorithm ID, followed by the checksum as a `u64`, see
+/// [`DynamicChecksum`]. Writing computes only the algorithm of `C`; since the stored algorithm is
+/// only known at the end, reading computes every enabled algorithm over the data.
+///
+/// Because of the algorithm ID, the layout differs from that of `C` itself: `Dynamic<C>` can not
+/// read data written with a plain config, such as `Crc32fast`, nor the other way around. Existing
+/// data has to be rewritten once with a `Dynamic` config to benefit from it.
+///
+/// Example:
+#[cfg_attr(not(feature = "crc32fast"), doc = "```ignore")]
+#[cfg_attr(feature = "crc32fast", doc = "```rust")]
+/// use codeq::config::{Crc32fast, Dynamic, DynamicAlgorithm};
+/// use codeq::{Decode, Encode, WithChecksum};
+///
+/// let buf = WithChecksum::<Dynamic<Crc32fast>, u64>::new(5).encode_to_vec().unwrap();
+/// assert_eq!(buf[8], Crc32fast::ID);
+///
+/// let got = WithChecksum::<Dynamic<Crc32fast>, u64>::decode(buf.as_slice()).unwrap();
+/// assert_eq!(got.into_inner(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dynamic<C>(PhantomData<C>);
+
+impl<C> CodeqConfig for Dynamic<C>
+where C: DynamicAlgorithm
+{
+    type Hasher = DynamicHasher<C>;
+
+    const ALGORITHM: &'static str = "dynamic";
+    const BYTE_ORDER: ByteOrder = C::BYTE_ORDER;
+}
+
+/// A built-in config that [`Dynamic`] writes and reads, identified by a stable ID.
+///
+/// This trait is sealed: a reader has to be able to compute every algorithm it may find.
+pub trait DynamicAlgorithm: CodeqConfig + private::Sealed {
+    /// The ID stored with the checksum. It never changes once assigned.
+    const ID: u8;
+}
+
+mod private {
+    use crate::config::CodeqConfig;
+
+    pub trait Sealed: CodeqConfig {
+        /// Creates a hasher, which needs no key.
+        fn new_hasher() -> Self::Hasher;
+
+        /// Returns the checksum of `hasher` as a `u64`.
+        fn finalize_u64(hasher: Self::Hasher) -> u64;
+    }
+}
+
+/// The checksum stored by [`Dynamic`]: the ID of the algorithm, then the checksum as a `u64`,
+/// zero-extended for a 32-bit algorithm.
+///
+/// Decoding an ID of an unknown or disabled algorithm fails with
+/// [`Error::InvalidTag`](crate::Error::InvalidTag).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DynamicChecksum {
+    id: u8,
+    value: u64,
+}
+
+impl DynamicChecksum {
+    /// The [`DynamicAlgorithm::ID`] of the algorithm.
+    pub fn algorithm_id(&self) -> u8 {
+        self.id
+    }
+
+    /// The checksum.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl FixedSize for DynamicChecksum {
+    fn encoded_size() -> usize {
+        1 + 8
+    }
+}
+
+impl Encode for DynamicChecksum {
+    fn encode<W: io::Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: io::Write>(
+        &self,
+        order: ByteOrder,
+        mut w: W,
+    ) -> Result<usize, io::Error> {
+        Ok(self.id.encode_with_order(order, &mut w)?
+            + self.value.encode_with_order(order, &mut w)?)
+    }
+}
+
+impl Decode for DynamicChecksum {
+    fn decode<R: io::Read>(r: R) -> Result<Self, io::Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: io::Read>(order: ByteOrder, mut r: R) -> Result<Self, io::Error> {
+        let id = u8::decode(&mut r)?;
+        if algorithm_name(id).is_none() {
+            return Err(crate::Error::InvalidTag {
+                type_name: "DynamicChecksum",
+                tag: id as u64,
+            }
+            .into());
+        }
+
+        Ok(Self {
+            id,
+            value: u64::decode_with_order(order, &mut r)?,
+        })
+    }
+}
+
+macro_rules! dynamic_algorithms {
+    ($($feature:literal => $field:ident: $config:ident = $id:literal),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl private::Sealed for crate::config::$config {
+                fn new_hasher() -> Self::Hasher {
+                    UnkeyedChecksum::new()
+                }
+
+                fn finalize_u64(hasher: Self::Hasher) -> u64 {
+                    u64::from(hasher.finalize())
+                }
+            }
+
+            #[cfg(feature = $feature)]
+            impl DynamicAlgorithm for crate::config::$config {
+                const ID: u8 = $id;
+            }
+        )*
+
+        /// The enabled algorithms other than that of `C`, each computed only if needed.
+        struct Others {
+            $(
+                #[cfg(feature = $feature)]
+                $field: Option<<crate::config::$config as CodeqConfig>::Hasher>,
+            )*
+        }
+
+        impl Others {
+            /// Creates the checksums of the algorithms whose ID matches `computed`.
+            #[allow(unused_variables)]
+            fn with_algorithms(computed: impl Fn(u8) -> bool) -> Self {
+                Self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        $field: if computed($id) {
+                            Some(UnkeyedChecksum::new())
+                        } else {
+                            None
+                        },
+                    )*
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn update(&mut self, bytes: &[u8]) {
+                $(
+                    #[cfg(feature = $feature)]
+                    if let Some(h) = &mut self.$field {
+                        h.update(bytes);
+                    }
+                )*
+            }
+
+            /// Returns the checksum of the algorithm `id`, if it is computed.
+            fn finalize_id(self, id: u8) -> Option<u64> {
+                match id {
+                    $(
+                        #[cfg(feature = $feature)]
+                        $id => self.$field.map(|h| u64::from(h.finalize())),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+
+        /// The name of the algorithm `id`, i.e., its [`CodeqConfig::ALGORITHM`].
+        fn algorithm_name(id: u8) -> Option<&'static str> {
+            match id {
+                $(
+                    #[cfg(feature = $feature)]
+                    $id => Some(<crate::config::$config as CodeqConfig>::ALGORITHM),
+                )*
+                _ => None,
+            }
+        }
+    };
+}
+
+dynamic_algorithms! {
+    "crc32fast" => crc32: Crc32fast = 1,
+    "crc64fast-nvme" => crc64_nvme: Crc64fastNvme = 2,
+    "crc32c" => crc32c: Crc32c = 3,
+    "xxh3" => xxh3: Xxh3 = 4,
+    "xxh64" => xxh64: Xxh64 = 5,
+}
+
+/// The [`Checksum`] of [`Dynamic`].
+///
+/// The checksum of `C` is computed and written; to verify, every enabled [`DynamicAlgorithm`] is
+/// computed, and the checksum of the algorithm named by the stored [`DynamicChecksum`] is
+/// compared. A stored algorithm that is not computed, i.e., other than `C` with a hasher created
+/// by [`new()`](UnkeyedChecksum::new), is reported as a mismatch with the checksum of `C`.
+pub struct DynamicHasher<C>
+where C: DynamicAlgorithm
+{
+    hasher: C::Hasher,
+    others: Others,
+}
+
+impl<C> Checksum for DynamicHasher<C>
+where C: DynamicAlgorithm
+{
+    type Output = DynamicChecksum;
+
+    fn with_options(_options: &ChecksumOptions) -> io::Result<Self> {
+        Ok(Self::new())
+    }
+
+    fn with_options_for_verify(_options: &ChecksumOptions) -> io::Result<Self> {
+        Ok(Self::new_for_verify())
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.hasher.update(bytes);
+        self.others.update(bytes);
+    }
+
+    fn finalize(self) -> DynamicChecksum {
+        DynamicChecksum {
+            id: C::ID,
+            value: C::finalize_u64(self.hasher),
+        }
+    }
+
+    fn verify(self, stored: &DynamicChecksum) -> Result<(), DynamicChecksum> {
+        let computed = match self.others.finalize_id(stored.id) {
+            Some(value) => DynamicChecksum {
+                id: stored.id,
+                value,
+            },
+            None => DynamicChecksum {
+                id: C::ID,
+                value: C::finalize_u64(self.hasher),
+            },
+        };
+
+        if computed == *stored {
+            Ok(())
+        } else {
+            Err(computed)
+        }
+    }
+
+    fn stored_algorithm(stored: &DynamicChecksum) -> Option<&'static str> {
+        algorithm_name(stored.id)
+    }
+}
+
+impl<C> UnkeyedChecksum for DynamicHasher<C>
+where C: DynamicAlgorithm
+{
+    fn new() -> Self {
+        Self {
+            hasher: C::new_hasher(),
+            others: Others::with_algorithms(|_| false),
+        }
+    }
+
+    fn new_for_verify() -> Self {
+        Self {
+            hasher: C::new_hasher(),
+            others: Others::with_algorithms(|id| id != C::ID),
+        }
+    }
+}
+
+#[cfg(feature = "crc32fast")]
+#[cfg(test)]
+mod tests {
+    use crate::config::CodeqConfig;
+    use crate::config::Crc32fast;
+    use crate::config::Dynamic;
+    use crate::testing::test_codec;
+    use crate::Decode;
+    use crate::Encode;
+    use crate::Error;
+    use crate::FixedSize;
+    use crate::WithChecksum;
+
+    #[test]
+    fn test_dynamic_codec() -> anyhow::Result<()> {
+        let wc = Dynamic::<Crc32fast>::wrap(5u64);
+        assert_eq!(17, WithChecksum::<Dynamic<Crc32fast>, u64>::encoded_size());
+
+        let b = vec![
+            0, 0, 0, 0, 0, 0, 0, 5, // data
+            1, // algorithm
+            0, 0, 0, 0, 21, 72, 43, 230, // checksum
+        ];
+
+        test_codec(b.as_slice(), &wc)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dynamic_unknown_algorithm() -> anyhow::Result<()> {
+        let b = vec![
+            0, 0, 0, 0, 0, 0, 0, 5,  // data
+            99, // algorithm
+            0, 0, 0, 0, 21, 72, 43, 230, // checksum
+        ];
+
+        let err = WithChecksum::<Dynamic<Crc32fast>, u64>::decode(b.as_slice()).unwrap_err();
+        assert_eq!(
+            Some(&Error::InvalidTag {
+                type_name: "DynamicChecksum",
+                tag: 99
+            }),
+            Error::from_io(&err)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dynamic_reads_no_plain_data() -> anyhow::Result<()> {
+        let b = Crc32fast::wrap(5u64).encode_to_vec()?;
+
+        let res = WithChecksum::<Dynamic<Crc32fast>, u64>::decode(b.as_slice());
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "crc64fast-nvme")]
+    #[test]
+    fn test_dynamic_hasher_algorithms() -> anyhow::Result<()> {
+        use crate::config::DynamicHasher;
+        use crate::config::UnkeyedChecksum;
+
+        // Writing computes only the algorithm of C
+        let h = DynamicHasher::<Crc32fast>::new();
+        assert!(h.others.crc32.is_none());
+        assert!(h.others.crc64_nvme.is_none());
+
+        // Verifying computes the others too
+        let h = DynamicHasher::<Crc32fast>::new_for_verify();
+        assert!(h.others.crc32.is_none());
+        assert!(h.others.crc64_nvme.is_some());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "crc64fast-nvme")]
+    #[test]
+    fn test_dynamic_verify_not_computed() -> anyhow::Result<()> {
+        use crate::config::Checksum;
+        use crate::config::Crc64fastNvme;
+        use crate::config::DynamicAlgorithm;
+        use crate::config::DynamicHasher;
+        use crate::config::UnkeyedChecksum;
+
+        let mut h = DynamicHasher::<Crc64fastNvme>::new();
+        h.update(b"foo");
+        let stored = h.finalize();
+
+        // A hasher created with new() computes only CRC32: the stored CRC64 is a mismatch
+        let mut h = DynamicHasher::<Crc32fast>::new();
+        h.update(b"foo");
+        let computed = h.verify(&stored).unwrap_err();
+        assert_eq!(Crc32fast::ID, computed.algorithm_id());
+        assert_eq!(Crc32fast::hash(b"foo"), computed.value());
+
+        let mut h = DynamicHasher::<Crc32fast>::new_for_verify();
+        h.update(b"foo");
+        assert_eq!(Ok(()), h.verify(&stored));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "crc64fast-nvme")]
+    #[test]
+    fn test_dynamic_migrate() -> anyhow::Result<()> {
+        use crate::config::Crc64fastNvme;
+        use crate::Segment;
+
+        let old = Dynamic::<Crc32fast>::segment(5, 10).encode_to_vec()?;
+        let new = Dynamic::<Crc64fastNvme>::segment(5, 10).encode_to_vec()?;
+
+        assert_eq!(1, old[16]);
+        assert_eq!(2, new[16]);
+        assert_eq!(&Crc64fastNvme::hash(&new[..16]).to_be_bytes(), &new[17..]);
+
+        // Either reader reads both
+        for b in [&old, &new] {
+            let s = Segment::<Dynamic<Crc32fast>>::decode(b.as_slice())?;
+            assert_eq!((5, 10), (s.offset, s.size));
+
+            let s = Segment::<Dynamic<Crc64fastNvme>>::decode(b.as_slice())?;
+            assert_eq!((5, 10), (s.offset, s.size));
+        }
+
+        // The checksum is verified with the stored algorithm
+        let mut b = new.clone();
+        b[16] = 1;
+        let err = Segment::<Dynamic<Crc64fastNvme>>::decode(b.as_slice()).unwrap_err();
+        assert!(err.to_string().starts_with("crc32 checksum mismatch: stored 0x01"));
+        assert!(matches!(
+            Error::from_io(&err),
+            Some(Error::ChecksumMismatch {
+                algorithm: "crc32",
+                ..
+            })
+        ));
+
+        Ok(())
+    }
+}
@@ -4,17 +4,144 @@ use core::hash::Hasher;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::io;
+use std::marker::PhantomData;
 
+use crate::ByteOrder;
+use crate::ChecksumOptions;
 use crate::ChecksumReader;
 use crate::ChecksumWriter;
+use crate::Decode;
+use crate::Encode;
+use crate::FixedSize;
 use crate::Segment;
 use crate::WithChecksum;
 
+mod dynamic;
+
+pub use dynamic::Dynamic;
+pub use dynamic::DynamicAlgorithm;
+pub use dynamic::DynamicChecksum;
+pub use dynamic::DynamicHasher;
+
+/// A checksum algorithm: accumulates the bytes fed to it and produces a checksum of type
+/// [`Output`](Self::Output).
+///
+/// Every [`Hasher`] that implements [`Default`] is a `Checksum` with a `u64` output, so that any
+/// 64-bit hasher can be used as [`CodeqConfig::Hasher`]. Digests wider than 64 bits, such as
+/// BLAKE3 or SHA-256, implement this trait with an array output.
+pub trait Checksum {
+    /// The checksum, appended to the protected data with its [`Encode`] impl, in the byte order
+    /// of the config. Its [`FixedSize::encoded_size()`] is the number of bytes of the checksum.
+    type Output: Encode + Decode + FixedSize + Copy + Eq + Debug;
+
+    /// Creates the initial state, before any data is fed, with the given options.
+    ///
+    /// A keyed checksum takes its key from `options`, and fails with
+    /// [`Error::MissingChecksumKey`](crate::Error::MissingChecksumKey) if there is none. A
+    /// checksum that needs no key also implements [`UnkeyedChecksum`], which can not fail.
+    fn with_options(options: &ChecksumOptions) -> io::Result<Self>
+    where Self: Sized;
+
+    /// Creates the initial state of a checksum to [`verify()`](Self::verify), as
+    /// [`ChecksumReader`] does; the same as [`with_options()`](Self::with_options) by default.
+    ///
+    /// [`DynamicHasher`] overrides it to compute every algorithm the stored checksum may name,
+    /// while [`with_options()`](Self::with_options) computes only the one it writes.
+    fn with_options_for_verify(options: &ChecksumOptions) -> io::Result<Self>
+    where Self: Sized {
+        Self::with_options(options)
+    }
+
+    /// Feeds `bytes` to the checksum.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Returns the checksum of all bytes fed.
+    fn finalize(self) -> Self::Output;
+
+    /// Checks the checksum of all bytes fed against a `stored` one, returning the computed
+    /// checksum if they differ.
+    ///
+    /// Keyed checksums override it to compare in constant time, so that the time taken does not
+    /// reveal how much of a forged tag is correct. [`DynamicHasher`] computes the checksum of the
+    /// algorithm named by `stored`.
+    fn verify(self, stored: &Self::Output) -> Result<(), Self::Output>
+    where Self: Sized {
+        let computed = self.finalize();
+        if computed == *stored {
+            Ok(())
+        } else {
+            Err(computed)
+        }
+    }
+
+    /// The name of the algorithm of a `stored` checksum, reported when it does not match; `None`,
+    /// the default, reports the [`CodeqConfig::ALGORITHM`] of the config.
+    ///
+    /// [`DynamicHasher`] names the algorithm whose ID is stored.
+    fn stored_algorithm(stored: &Self::Output) -> Option<&'static str>
+    where Self: Sized {
+        let _ = stored;
+        None
+    }
+}
+
+/// A [`Checksum`] that needs no key, and thus is created without [`ChecksumOptions`] and without
+/// failing.
+///
+/// It is what [`CodeqConfig::hash()`], [`CodeqConfig::new_writer()`] and
+/// [`CodeqConfig::new_reader()`] require; a keyed checksum is created with
+/// [`ChecksumWriter::with_options()`] or [`ChecksumReader::with_options()`] instead.
+pub trait UnkeyedChecksum: Checksum {
+    /// Creates the initial state, before any data is fed.
+    fn new() -> Self;
+
+    /// Creates the initial state of a checksum to [`verify()`](Checksum::verify); the same as
+    /// [`new()`](Self::new) by default, see [`Checksum::with_options_for_verify()`].
+    fn new_for_verify() -> Self
+    where Self: Sized {
+        Self::new()
+    }
+}
+
+impl<H> Checksum for H
+where H: Hasher + Default
+{
+    type Output = u64;
+
+    fn with_options(_options: &ChecksumOptions) -> io::Result<Self> {
+        Ok(H::default())
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.write(bytes);
+    }
+
+    fn finalize(self) -> u64 {
+        self.finish()
+    }
+}
+
+impl<H> UnkeyedChecksum for H
+where H: Hasher + Default
+{
+    fn new() -> Self {
+        H::default()
+    }
+}
+
+/// Compares two tags without branching on their content.
+#[cfg(any(feature = "blake3", feature = "hmac"))]
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let diff = a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    a.len() == b.len() && std::hint::black_box(diff) == 0
+}
+
 /// Static Configuration for checksum calculation and verification.
 ///
 /// This trait defines how checksums are calculated and verified for data integrity.
 /// It allows applications to:
-/// - Choose their preferred checksum algorithm (e.g., CRC32, CRC64)
+/// - Choose their preferred checksum algorithm (e.g., CRC32, CRC64, or a keyed MAC such as
+///   HMAC-SHA256)
 /// - Create checksum-enabled readers and writers
 /// - Wrap data with checksums
 ///
@@ -50,23 +177,43 @@ use crate::WithChecksum;
 pub trait CodeqConfig
 where Self: Debug + Clone + Copy + Default + PartialEq + Eq + PartialOrd + Ord + Hash + Sized
 {
-    /// The hasher type used for checksum calculation.
-    type Hasher: Hasher + Default;
+    /// The checksum algorithm, any [`Hasher`] + [`Default`] for a 64-bit checksum, or a
+    /// [`Checksum`] with a wider output.
+    type Hasher: Checksum;
+
+    /// The name of the checksum algorithm, used in error messages.
+    const ALGORITHM: &'static str = "custom";
+
+    /// The byte order of the checksum, and of integers and length prefixes encoded in
+    /// [`WithChecksum`] and [`Segment`].
+    ///
+    /// Defaults to [`ByteOrder::BigEndian`]; see [`LittleEndian`] for a little-endian variant of
+    /// an existing config.
+    const BYTE_ORDER: ByteOrder = ByteOrder::BigEndian;
 
     /// Calculates a checksum for the given buffer.
-    fn hash(buf: &[u8]) -> u64 {
-        let mut hasher = Self::Hasher::default();
-        hasher.write(buf);
-        hasher.finish()
+    fn hash(buf: &[u8]) -> <Self::Hasher as Checksum>::Output
+    where Self::Hasher: UnkeyedChecksum {
+        let mut hasher = <Self::Hasher as UnkeyedChecksum>::new();
+        hasher.update(buf);
+        hasher.finalize()
     }
 
-    /// Creates a new checksum writer wrapping the given writer.
-    fn new_writer<W: io::Write>(inner: W) -> ChecksumWriter<Self, W> {
+    /// The number of bytes of the checksum appended to the data, declared by the
+    /// [`Output`](Checksum::Output) of the [`Hasher`](Self::Hasher), e.g., 8 for a `u64`.
+    fn checksum_size() -> usize {
+        <<Self::Hasher as Checksum>::Output as FixedSize>::encoded_size()
+    }
+
+    /// Creates a new checksum writer wrapping the given writer, see [`ChecksumWriter::new()`].
+    fn new_writer<W: io::Write>(inner: W) -> ChecksumWriter<Self, W>
+    where Self::Hasher: UnkeyedChecksum {
         ChecksumWriter::new(inner)
     }
 
-    /// Creates a new checksum reader wrapping the given reader.
-    fn new_reader<R: io::Read>(inner: R) -> ChecksumReader<Self, R> {
+    /// Creates a new checksum reader wrapping the given reader, see [`ChecksumReader::new()`].
+    fn new_reader<R: io::Read>(inner: R) -> ChecksumReader<Self, R>
+    where Self::Hasher: UnkeyedChecksum {
         ChecksumReader::new(inner)
     }
 
@@ -81,12 +228,167 @@ where Self: Debug + Clone + Copy + Default + PartialEq + Eq + PartialOrd + Ord +
     }
 }
 
+/// Little-endian variant of config `C`.
+///
+/// Uses the same checksum algorithm as `C`, but sets [`CodeqConfig::BYTE_ORDER`] to
+/// [`ByteOrder::LittleEndian`].
+#[cfg_attr(not(feature = "crc32fast"), doc = "```ignore")]
+#[cfg_attr(feature = "crc32fast", doc = "```rust")]
+/// use codeq::config::{CodeqConfig, Crc32fast, LittleEndian};
+/// use codeq::Encode;
+///
+/// let buf = LittleEndian::<Crc32fast>::wrap(1u32).encode_to_vec().unwrap();
+/// assert_eq!(&buf[..4], &[1, 0, 0, 0]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LittleEndian<C>(PhantomData<C>);
+
+impl<C> CodeqConfig for LittleEndian<C>
+where C: CodeqConfig
+{
+    type Hasher = C::Hasher;
+
+    const ALGORITHM: &'static str = C::ALGORITHM;
+    const BYTE_ORDER: ByteOrder = ByteOrder::LittleEndian;
+}
+
+/// Variant of config `C` whose checksum is seeded with the domain tag `DOMAIN` and the
+/// [`ChecksumOptions::salt`].
+///
+/// Data of different types can be encoded the same; giving each type its own `DOMAIN`, and each
+/// file its own salt, makes the checksum reject data decoded as the wrong type, or from the wrong
+/// file, instead of passing verification. The seed is hashed before the data but not written.
+/// The plain codecs use a salt of 0; pass another one with `encode_with_options()` and
+/// `decode_with_options()`.
+#[cfg_attr(not(feature = "crc32fast"), doc = "```ignore")]
+#[cfg_attr(feature = "crc32fast", doc = "```rust")]
+/// use codeq::config::{Crc32fast, Seeded};
+/// use codeq::{ChecksumOptions, Decode, Encode, WithChecksum};
+///
+/// type Header = WithChecksum<Seeded<Crc32fast, 1>, (u64, u64)>;
+/// type Footer = WithChecksum<Seeded<Crc32fast, 2>, (u64, u64)>;
+///
+/// let buf = Header::new((1, 2)).encode_to_vec().unwrap();
+/// assert!(Header::decode(buf.as_slice()).is_ok());
+/// assert!(Footer::decode(buf.as_slice()).is_err());
+///
+/// // Written with the salt of another file
+/// let options = ChecksumOptions {
+///     salt: 7,
+///     ..ChecksumOptions::default()
+/// };
+/// assert!(Header::decode_with_options(&options, buf.as_slice()).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Seeded<C, const DOMAIN: u64>(PhantomData<C>);
+
+impl<C, const DOMAIN: u64> CodeqConfig for Seeded<C, DOMAIN>
+where C: CodeqConfig
+{
+    type Hasher = SeededHasher<C::Hasher, DOMAIN>;
+
+    const ALGORITHM: &'static str = C::ALGORITHM;
+    const BYTE_ORDER: ByteOrder = C::BYTE_ORDER;
+}
+
+/// The [`Checksum`] of [`Seeded`], feeding `DOMAIN` and the [`ChecksumOptions::salt`] to `H`,
+/// both as big-endian `u64`, before the data.
+#[derive(Debug, Clone)]
+pub struct SeededHasher<H, const DOMAIN: u64>(H);
+
+impl<H, const DOMAIN: u64> SeededHasher<H, DOMAIN>
+where H: Checksum
+{
+    fn seed(mut h: H, options: &ChecksumOptions) -> Self {
+        h.update(&DOMAIN.to_be_bytes());
+        h.update(&options.salt.to_be_bytes());
+        Self(h)
+    }
+}
+
+impl<H, const DOMAIN: u64> Checksum for SeededHasher<H, DOMAIN>
+where H: Checksum
+{
+    type Output = H::Output;
+
+    fn with_options(options: &ChecksumOptions) -> io::Result<Self> {
+        Ok(Self::seed(H::with_options(options)?, options))
+    }
+
+    fn with_options_for_verify(options: &ChecksumOptions) -> io::Result<Self> {
+        Ok(Self::seed(H::with_options_for_verify(options)?, options))
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn finalize(self) -> H::Output {
+        self.0.finalize()
+    }
+
+    fn verify(self, stored: &H::Output) -> Result<(), H::Output> {
+        self.0.verify(stored)
+    }
+
+    fn stored_algorithm(stored: &H::Output) -> Option<&'static str> {
+        H::stored_algorithm(stored)
+    }
+}
+
+/// Seeded with a salt of 0; see [`ChecksumOptions::salt`].
+impl<H, const DOMAIN: u64> UnkeyedChecksum for SeededHasher<H, DOMAIN>
+where H: UnkeyedChecksum
+{
+    fn new() -> Self {
+        Self::seed(H::new(), &ChecksumOptions::default())
+    }
+
+    fn new_for_verify() -> Self {
+        Self::seed(H::new_for_verify(), &ChecksumOptions::default())
+    }
+}
+
 #[cfg(feature = "crc32fast")]
 pub mod crc32fast_impl {
+    use std::io;
+
+    use super::Checksum;
+    use super::ChecksumOptions;
     use super::CodeqConfig;
+    use super::UnkeyedChecksum;
+
+    /// The [`Checksum`] of [`Crc32fastU32`], with a `u32` output.
+    #[derive(Default, Clone)]
+    pub struct Crc32fastHasher(crc32fast::Hasher);
+
+    impl Checksum for Crc32fastHasher {
+        type Output = u32;
+
+        fn with_options(_options: &ChecksumOptions) -> io::Result<Self> {
+            Ok(Self::new())
+        }
+
+        fn update(&mut self, bytes: &[u8]) {
+            self.0.update(bytes);
+        }
+
+        fn finalize(self) -> u32 {
+            self.0.finalize()
+        }
+    }
+
+    impl UnkeyedChecksum for Crc32fastHasher {
+        fn new() -> Self {
+            Self::default()
+        }
+    }
 
     /// CRC32 checksum implementation.
     ///
+    /// The checksum is stored as a `u64` in 8 bytes, the upper 4 of which are zero; see
+    /// [`Crc32fastU32`] for a 4-byte checksum.
+    ///
     /// Derives standard traits to allow this type to be used as a generic type parameter,
     /// when the containing type requires these bounds.
     #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -94,11 +396,28 @@ pub mod crc32fast_impl {
 
     impl CodeqConfig for Crc32fast {
         type Hasher = crc32fast::Hasher;
+
+        const ALGORITHM: &'static str = "crc32";
+    }
+
+    /// CRC32 checksum implementation, stored as a `u32` in 4 bytes.
+    ///
+    /// The checksum is the same as [`Crc32fast`], without the 4 zero bytes, so data written
+    /// with one config can not be read with the other.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Crc32fastU32;
+
+    impl CodeqConfig for Crc32fastU32 {
+        type Hasher = Crc32fastHasher;
+
+        const ALGORITHM: &'static str = "crc32";
     }
 }
 
 #[cfg(feature = "crc32fast")]
 pub use crc32fast_impl::Crc32fast;
+#[cfg(feature = "crc32fast")]
+pub use crc32fast_impl::Crc32fastU32;
 
 #[cfg(feature = "crc64fast-nvme")]
 mod crc64fast_nvme_impl {
@@ -126,8 +445,337 @@ mod crc64fast_nvme_impl {
 
     impl CodeqConfig for Crc64fastNvme {
         type Hasher = Crc64fastNvmeHasher;
+
+        const ALGORITHM: &'static str = "crc64-nvme";
     }
 }
 
 #[cfg(feature = "crc64fast-nvme")]
 pub use crc64fast_nvme_impl::Crc64fastNvme;
+
+#[cfg(feature = "crc32c")]
+mod crc32c_impl {
+    use std::io;
+
+    use crate::config::Checksum;
+    use crate::config::CodeqConfig;
+    use crate::config::UnkeyedChecksum;
+    use crate::ChecksumOptions;
+
+    /// The [`Checksum`] of [`Crc32cU32`], with a `u32` output.
+    #[derive(Default, Clone)]
+    pub struct Crc32cHasher(u32);
+
+    impl Checksum for Crc32cHasher {
+        type Output = u32;
+
+        fn with_options(_options: &ChecksumOptions) -> io::Result<Self> {
+            Ok(Self::new())
+        }
+
+        fn update(&mut self, bytes: &[u8]) {
+            self.0 = crc32c::crc32c_append(self.0, bytes);
+        }
+
+        fn finalize(self) -> u32 {
+            self.0
+        }
+    }
+
+    impl UnkeyedChecksum for Crc32cHasher {
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    /// CRC32C (Castagnoli) checksum implementation, hardware accelerated on x86_64 and aarch64.
+    ///
+    /// Like [`Crc32fast`](crate::config::Crc32fast), the checksum is stored as a `u64` in 8
+    /// bytes; see [`Crc32cU32`] for a 4-byte checksum.
+    ///
+    /// Derives standard traits to allow this type to be used as a generic type parameter,
+    /// when the containing type requires these bounds.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Crc32c;
+
+    impl CodeqConfig for Crc32c {
+        type Hasher = crc32c::Crc32cHasher;
+
+        const ALGORITHM: &'static str = "crc32c";
+    }
+
+    /// CRC32C checksum implementation, stored as a `u32` in 4 bytes.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Crc32cU32;
+
+    impl CodeqConfig for Crc32cU32 {
+        type Hasher = Crc32cHasher;
+
+        const ALGORITHM: &'static str = "crc32c";
+    }
+}
+
+#[cfg(feature = "crc32c")]
+pub use crc32c_impl::Crc32c;
+#[cfg(feature = "crc32c")]
+pub use crc32c_impl::Crc32cU32;
+
+#[cfg(feature = "xxh3")]
+mod xxh3_impl {
+    use crate::config::CodeqConfig;
+
+    /// XXH3 64-bit hash implementation, with the default secret and seed 0.
+    ///
+    /// Derives standard traits to allow this type to be used as a generic type parameter,
+    /// when the containing type requires these bounds.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Xxh3;
+
+    impl CodeqConfig for Xxh3 {
+        type Hasher = xxhash_rust::xxh3::Xxh3;
+
+        const ALGORITHM: &'static str = "xxh3-64";
+    }
+}
+
+#[cfg(feature = "xxh3")]
+pub use xxh3_impl::Xxh3;
+
+#[cfg(feature = "xxh64")]
+mod xxh64_impl {
+    use crate::config::CodeqConfig;
+
+    /// XXH64 hash implementation, with seed 0.
+    ///
+    /// Derives standard traits to allow this type to be used as a generic type parameter,
+    /// when the containing type requires these bounds.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Xxh64;
+
+    impl CodeqConfig for Xxh64 {
+        type Hasher = xxhash_rust::xxh64::Xxh64;
+
+        const ALGORITHM: &'static str = "xxh64";
+    }
+}
+
+#[cfg(feature = "xxh64")]
+pub use xxh64_impl::Xxh64;
+
+#[cfg(feature = "blake3")]
+mod blake3_impl {
+    use std::io;
+
+    use crate::config::constant_time_eq;
+    use crate::config::Checksum;
+    use crate::config::CodeqConfig;
+    use crate::config::UnkeyedChecksum;
+    use crate::ChecksumOptions;
+
+    #[derive(Clone)]
+    pub struct Blake3Hasher(blake3::Hasher);
+
+    impl Checksum for Blake3Hasher {
+        type Output = [u8; 32];
+
+        fn with_options(_options: &ChecksumOptions) -> io::Result<Self> {
+            Ok(Self::new())
+        }
+
+        fn update(&mut self, bytes: &[u8]) {
+            self.0.update(bytes);
+        }
+
+        fn finalize(self) -> [u8; 32] {
+            *self.0.finalize().as_bytes()
+        }
+    }
+
+    impl UnkeyedChecksum for Blake3Hasher {
+        fn new() -> Self {
+            Self(blake3::Hasher::new())
+        }
+    }
+
+    #[derive(Clone)]
+    pub struct Blake3KeyedHasher(blake3::Hasher);
+
+    impl Checksum for Blake3KeyedHasher {
+        type Output = [u8; 32];
+
+        fn with_options(options: &ChecksumOptions) -> io::Result<Self> {
+            let key = options.key.as_ref().ok_or(crate::Error::MissingChecksumKey {
+                algorithm: Blake3Keyed::ALGORITHM,
+            })?;
+            Ok(Self(blake3::Hasher::new_keyed(key.as_bytes())))
+        }
+
+        fn update(&mut self, bytes: &[u8]) {
+            self.0.update(bytes);
+        }
+
+        fn finalize(self) -> [u8; 32] {
+            *self.0.finalize().as_bytes()
+        }
+
+        fn verify(self, stored: &[u8; 32]) -> Result<(), [u8; 32]> {
+            let computed = self.finalize();
+            if constant_time_eq(&computed, stored) {
+                Ok(())
+            } else {
+                Err(computed)
+            }
+        }
+    }
+
+    /// BLAKE3 digest, a 256-bit checksum that is infeasible to forge without changing it.
+    ///
+    /// Derives standard traits to allow this type to be used as a generic type parameter,
+    /// when the containing type requires these bounds.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Blake3;
+
+    impl CodeqConfig for Blake3 {
+        type Hasher = Blake3Hasher;
+
+        const ALGORITHM: &'static str = "blake3";
+    }
+
+    /// Keyed BLAKE3, a 256-bit authentication tag with the key of the [`ChecksumOptions`].
+    ///
+    /// Creating a reader or writer, or encoding or decoding with this config, fails with
+    /// [`Error::MissingChecksumKey`](crate::Error::MissingChecksumKey) if the options have no key.
+    ///
+    /// Derives standard traits to allow this type to be used as a generic type parameter,
+    /// when the containing type requires these bounds.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Blake3Keyed;
+
+    impl CodeqConfig for Blake3Keyed {
+        type Hasher = Blake3KeyedHasher;
+
+        const ALGORITHM: &'static str = "blake3-keyed";
+    }
+}
+
+#[cfg(feature = "blake3")]
+pub use blake3_impl::Blake3;
+#[cfg(feature = "blake3")]
+pub use blake3_impl::Blake3Keyed;
+
+#[cfg(feature = "sha2")]
+mod sha2_impl {
+    use std::io;
+
+    use sha2::Digest;
+
+    use crate::config::Checksum;
+    use crate::config::CodeqConfig;
+    use crate::config::UnkeyedChecksum;
+    use crate::ChecksumOptions;
+
+    #[derive(Clone)]
+    pub struct Sha256Hasher(sha2::Sha256);
+
+    impl Checksum for Sha256Hasher {
+        type Output = [u8; 32];
+
+        fn with_options(_options: &ChecksumOptions) -> io::Result<Self> {
+            Ok(Self::new())
+        }
+
+        fn update(&mut self, bytes: &[u8]) {
+            self.0.update(bytes);
+        }
+
+        fn finalize(self) -> [u8; 32] {
+            self.0.finalize().into()
+        }
+    }
+
+    impl UnkeyedChecksum for Sha256Hasher {
+        fn new() -> Self {
+            Self(sha2::Sha256::new())
+        }
+    }
+
+    /// SHA-256 digest, a 256-bit checksum that is infeasible to forge without changing it.
+    ///
+    /// Derives standard traits to allow this type to be used as a generic type parameter,
+    /// when the containing type requires these bounds.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Sha256;
+
+    impl CodeqConfig for Sha256 {
+        type Hasher = Sha256Hasher;
+
+        const ALGORITHM: &'static str = "sha256";
+    }
+}
+
+#[cfg(feature = "sha2")]
+pub use sha2_impl::Sha256;
+
+#[cfg(feature = "hmac")]
+mod hmac_impl {
+    use std::io;
+
+    use hmac::Mac;
+
+    use crate::config::constant_time_eq;
+    use crate::config::Checksum;
+    use crate::config::CodeqConfig;
+    use crate::ChecksumOptions;
+
+    #[derive(Clone)]
+    pub struct HmacSha256Hasher(hmac::Hmac<sha2::Sha256>);
+
+    impl Checksum for HmacSha256Hasher {
+        type Output = [u8; 32];
+
+        fn with_options(options: &ChecksumOptions) -> io::Result<Self> {
+            let key = options.key.as_ref().ok_or(crate::Error::MissingChecksumKey {
+                algorithm: HmacSha256::ALGORITHM,
+            })?;
+            Ok(Self(
+                hmac::Hmac::new_from_slice(key.as_bytes()).expect("HMAC takes keys of any length"),
+            ))
+        }
+
+        fn update(&mut self, bytes: &[u8]) {
+            self.0.update(bytes);
+        }
+
+        fn finalize(self) -> [u8; 32] {
+            self.0.finalize().into_bytes().into()
+        }
+
+        fn verify(self, stored: &[u8; 32]) -> Result<(), [u8; 32]> {
+            let computed = self.finalize();
+            if constant_time_eq(&computed, stored) {
+                Ok(())
+            } else {
+                Err(computed)
+            }
+        }
+    }
+
+    /// HMAC-SHA256, a 256-bit authentication tag with the key of the [`ChecksumOptions`].
+    ///
+    /// Creating a reader or writer, or encoding or decoding with this config, fails with
+    /// [`Error::MissingChecksumKey`](crate::Error::MissingChecksumKey) if the options have no key.
+    ///
+    /// Derives standard traits to allow this type to be used as a generic type parameter,
+    /// when the containing type requires these bounds.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct HmacSha256;
+
+    impl CodeqConfig for HmacSha256 {
+        type Hasher = HmacSha256Hasher;
+
+        const ALGORITHM: &'static str = "hmac-sha256";
+    }
+}
+
+#[cfg(feature = "hmac")]
+pub use hmac_impl::HmacSha256;
@@ -0,0 +1,454 @@
+use std::cell::Cell;
+use std::io;
+use std::mem::size_of;
+
+use crate::error::Limit;
+
+thread_local! {
+    static STATE: Cell<State> = const { Cell::new(State::new(DecodeLimits::UNLIMITED)) };
+}
+
+/// Max number of bytes allocated up front for a decoded collection. Larger collections grow as
+/// their elements are actually read, so that a corrupted length prefix cannot allocate memory
+/// that is not backed by input.
+const PREALLOC_BYTES: usize = 64 * 1024;
+
+/// Limits on the resources a decoder may use, to decode untrusted input safely.
+///
+/// The limits apply to the built-in codecs of strings, sequences, maps, sets and smart pointers
+/// decoded within [`DecodeLimits::scope()`] on the current thread. Exceeding a limit returns an
+/// [`io::ErrorKind::InvalidData`] error before the memory is allocated.
+///
+/// Outside of a scope, decoding is unlimited. Either way, collections are allocated
+/// incrementally, so a length prefix larger than the input fails with
+/// [`io::ErrorKind::UnexpectedEof`] instead of allocating the claimed size.
+///
+/// Example:
+/// ```rust
+/// use codeq::{Decode, DecodeLimits, Encode};
+///
+/// let buf = vec![1u8; 100].encode_to_vec().unwrap();
+///
+/// let limits = DecodeLimits {
+///     max_len: 10,
+///     ..DecodeLimits::default()
+/// };
+/// let res = limits.scope(|| Vec::<u8>::decode(&mut buf.as_slice()));
+/// assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecodeLimits {
+    /// Max number of bytes of a single collection or string.
+    pub max_alloc: usize,
+
+    /// Max number of bytes of all collections and strings decoded in the scope.
+    pub max_total_alloc: usize,
+
+    /// Max number of elements of a single collection, or bytes of a string.
+    pub max_len: usize,
+
+    /// Max depth of nested collections and smart pointers.
+    pub max_depth: usize,
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
+impl DecodeLimits {
+    /// No limits.
+    pub const UNLIMITED: Self = Self {
+        max_alloc: usize::MAX,
+        max_total_alloc: usize::MAX,
+        max_len: usize::MAX,
+        max_depth: usize::MAX,
+    };
+
+    /// Returns the limits in effect on the current thread.
+    pub fn current() -> Self {
+        STATE.with(|s| s.get().limits)
+    }
+
+    /// Runs `f` with `self` as the limits, restoring the previous limits afterwards, even if `f`
+    /// panics.
+    ///
+    /// The total allocation and the depth are counted from zero in the outermost scope. A nested
+    /// scope applies the stricter of its own and the enclosing limits, and keeps counting the total
+    /// allocation and the depth of the enclosing scope, so it cannot loosen them.
+    ///
+    /// The limits are kept in a thread-local: they apply to decoding on the calling thread only.
+    /// Decoding moved to another thread, e.g., with `spawn_blocking()` or on a rayon pool, is
+    /// unlimited unless it runs in a scope entered on that thread, whose total allocation and
+    /// depth are counted from zero:
+    /// ```rust
+    /// use codeq::{Decode, DecodeLimits, Encode};
+    ///
+    /// let buf = vec![1u8; 100].encode_to_vec().unwrap();
+    /// let limits = DecodeLimits {
+    ///     max_len: 10,
+    ///     ..DecodeLimits::default()
+    /// };
+    ///
+    /// limits.scope(|| {
+    ///     let limits = DecodeLimits::current();
+    ///     let res = std::thread::spawn(move || {
+    ///         limits.scope(|| Vec::<u8>::decode(&mut buf.as_slice()))
+    ///     });
+    ///     assert!(res.join().unwrap().is_err());
+    /// });
+    /// ```
+    pub fn scope<T>(self, f: impl FnOnce() -> T) -> T {
+        let _restore = Restore(STATE.with(|s| {
+            let prev = s.get();
+            let next = if prev.in_scope {
+                State {
+                    limits: prev.limits.min(self),
+                    ..prev
+                }
+            } else {
+                State::new(self)
+            };
+            s.replace(State {
+                in_scope: true,
+                ..next
+            })
+        }));
+        f()
+    }
+
+    /// The stricter of every limit of `self` and `other`.
+    fn min(self, other: Self) -> Self {
+        Self {
+            max_alloc: self.max_alloc.min(other.max_alloc),
+            max_total_alloc: self.max_total_alloc.min(other.max_total_alloc),
+            max_len: self.max_len.min(other.max_len),
+            max_depth: self.max_depth.min(other.max_depth),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    limits: DecodeLimits,
+    total_alloc: usize,
+    depth: usize,
+    in_scope: bool,
+}
+
+impl State {
+    const fn new(limits: DecodeLimits) -> Self {
+        Self {
+            limits,
+            total_alloc: 0,
+            depth: 0,
+            in_scope: false,
+        }
+    }
+}
+
+/// Restores the state before a scope: an enclosing scope gets its limits back and keeps the
+/// counters, the state outside of any scope is restored as it was.
+struct Restore(State);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        STATE.with(|s| {
+            let prev = self.0;
+            if prev.in_scope {
+                let cur = s.get();
+                s.set(State {
+                    limits: prev.limits,
+                    ..cur
+                });
+            } else {
+                s.set(prev);
+            }
+        });
+    }
+}
+
+/// Checks that a collection of `len` elements of `T` is within the limits, and counts it in the
+/// total allocation.
+pub(crate) fn reserve<T>(len: usize) -> Result<(), io::Error> {
+    STATE.with(|s| {
+        let mut st = s.get();
+        let limits = st.limits;
+
+        if len > limits.max_len {
+            return Err(limit_exceeded(Limit::Len, len, limits.max_len));
+        }
+
+        let bytes = len.saturating_mul(size_of::<T>());
+        if bytes > limits.max_alloc {
+            return Err(limit_exceeded(Limit::Alloc, bytes, limits.max_alloc));
+        }
+
+        let total = st.total_alloc.saturating_add(bytes);
+        if total > limits.max_total_alloc {
+            return Err(limit_exceeded(
+                Limit::TotalAlloc,
+                total,
+                limits.max_total_alloc,
+            ));
+        }
+
+        st.total_alloc = total;
+        s.set(st);
+        Ok(())
+    })
+}
+
+/// Runs `f` one nesting level deeper, failing if it exceeds the max depth.
+pub(crate) fn nested<T>(f: impl FnOnce() -> Result<T, io::Error>) -> Result<T, io::Error> {
+    struct Leave;
+
+    impl Drop for Leave {
+        fn drop(&mut self) {
+            STATE.with(|s| {
+                let mut st = s.get();
+                st.depth -= 1;
+                s.set(st);
+            });
+        }
+    }
+
+    STATE.with(|s| {
+        let mut st = s.get();
+        if st.depth >= st.limits.max_depth {
+            return Err(limit_exceeded(
+                Limit::Depth,
+                st.depth + 1,
+                st.limits.max_depth,
+            ));
+        }
+        st.depth += 1;
+        s.set(st);
+        Ok(())
+    })?;
+
+    let _leave = Leave;
+    f()
+}
+
+/// The capacity to allocate up front for a collection of `len` elements of `T`.
+pub(crate) fn initial_capacity<T>(len: usize) -> usize {
+    len.min((PREALLOC_BYTES / size_of::<T>().max(1)).max(1))
+}
+
+fn limit_exceeded(limit: Limit, value: usize, max: usize) -> io::Error {
+    crate::Error::LimitExceeded { limit, value, max }.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::io;
+
+    use super::DecodeLimits;
+    use crate::Decode;
+    use crate::Encode;
+
+    #[test]
+    fn test_max_len() -> Result<(), io::Error> {
+        let limits = DecodeLimits {
+            max_len: 3,
+            ..DecodeLimits::default()
+        };
+
+        let buf = vec![1u64, 2, 3].encode_to_vec()?;
+        let got = limits.scope(|| Vec::<u64>::decode(&mut buf.as_slice()))?;
+        assert_eq!(vec![1, 2, 3], got);
+
+        let buf = "abcd".encode_to_vec()?;
+        let err = limits.scope(|| String::decode(&mut buf.as_slice())).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!("Collection length 4 exceeds limit 3", err.to_string());
+
+        let m = BTreeMap::from([(1u8, 1u8), (2, 2), (3, 3), (4, 4)]);
+        let buf = m.encode_to_vec()?;
+        let err = limits.scope(|| BTreeMap::<u8, u8>::decode(&mut buf.as_slice())).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_alloc() -> Result<(), io::Error> {
+        let limits = DecodeLimits {
+            max_alloc: 16,
+            ..DecodeLimits::default()
+        };
+
+        let buf = vec![1u64, 2].encode_to_vec()?;
+        limits.scope(|| Vec::<u64>::decode(&mut buf.as_slice()))?;
+
+        let buf = vec![1u64, 2, 3].encode_to_vec()?;
+        let err = limits.scope(|| Vec::<u64>::decode(&mut buf.as_slice())).unwrap_err();
+        assert_eq!("Allocation size 24 exceeds limit 16", err.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_total_alloc() -> Result<(), io::Error> {
+        let limits = DecodeLimits {
+            max_total_alloc: 10,
+            ..DecodeLimits::default()
+        };
+
+        let buf = vec!["abc".to_string(), "def".to_string()].encode_to_vec()?;
+
+        // The outer Vec takes 2 * size_of::<String>() bytes
+        let err = limits.scope(|| Vec::<String>::decode(&mut buf.as_slice())).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+        let buf = ("abcdef".to_string(), "ghij".to_string()).encode_to_vec()?;
+        limits.scope(|| <(String, String)>::decode(&mut buf.as_slice()))?;
+
+        let buf = ("abcdef".to_string(), "ghijk".to_string()).encode_to_vec()?;
+        let err = limits.scope(|| <(String, String)>::decode(&mut buf.as_slice())).unwrap_err();
+        assert_eq!(
+            "Total allocation size 11 exceeds limit 10; at:(1)",
+            err.to_string()
+        );
+
+        // Counted from zero in each outermost scope
+        let buf = "abcdef".encode_to_vec()?;
+        limits.scope(|| String::decode(&mut buf.as_slice()))?;
+        limits.scope(|| String::decode(&mut buf.as_slice()))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scope_is_thread_local() -> Result<(), io::Error> {
+        let buf = vec![1u8; 100].encode_to_vec()?;
+        let limits = DecodeLimits {
+            max_len: 10,
+            ..DecodeLimits::default()
+        };
+
+        limits.scope(|| {
+            assert_eq!(limits, DecodeLimits::current());
+
+            // The limits do not leak into another thread
+            let b = buf.clone();
+            let other = std::thread::spawn(move || {
+                (
+                    DecodeLimits::current(),
+                    Vec::<u8>::decode(&mut b.as_slice()).is_ok(),
+                )
+            });
+            assert_eq!((DecodeLimits::UNLIMITED, true), other.join().unwrap());
+
+            // Unless the other thread enters its own scope
+            let b = buf.clone();
+            let other =
+                std::thread::spawn(move || limits.scope(|| Vec::<u8>::decode(&mut b.as_slice())));
+            assert!(other.join().unwrap().is_err());
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_scope() -> Result<(), io::Error> {
+        let outer = DecodeLimits {
+            max_len: 3,
+            max_total_alloc: 10,
+            ..DecodeLimits::default()
+        };
+        let inner = DecodeLimits {
+            max_alloc: 5,
+            ..DecodeLimits::default()
+        };
+
+        let buf = "abcdef".encode_to_vec()?;
+
+        outer.scope(|| -> Result<(), io::Error> {
+            // The stricter limits of both scopes apply
+            inner.scope(|| {
+                assert_eq!(
+                    DecodeLimits {
+                        max_alloc: 5,
+                        max_total_alloc: 10,
+                        max_len: 3,
+                        max_depth: usize::MAX,
+                    },
+                    DecodeLimits::current()
+                );
+            });
+            assert_eq!(outer, DecodeLimits::current());
+
+            let buf = "abc".encode_to_vec()?;
+            String::decode(&mut buf.as_slice())?;
+            inner.scope(|| String::decode(&mut buf.as_slice()))?;
+
+            // The total allocation keeps counting across the nested scope: 3 + 3 + 3 + 3 > 10
+            String::decode(&mut buf.as_slice())?;
+            let err =
+                DecodeLimits::UNLIMITED.scope(|| String::decode(&mut buf.as_slice())).unwrap_err();
+            assert_eq!("Total allocation size 12 exceeds limit 10", err.to_string());
+
+            Ok(())
+        })?;
+
+        // A nested scope cannot loosen the enclosing one
+        let err = outer
+            .scope(|| DecodeLimits::UNLIMITED.scope(|| String::decode(&mut buf.as_slice())))
+            .unwrap_err();
+        assert_eq!("Collection length 6 exceeds limit 3", err.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_depth() -> Result<(), io::Error> {
+        let limits = DecodeLimits {
+            max_depth: 2,
+            ..DecodeLimits::default()
+        };
+
+        let buf = vec![vec![1u8]].encode_to_vec()?;
+        limits.scope(|| Vec::<Vec<u8>>::decode(&mut buf.as_slice()))?;
+
+        let buf = vec![vec![Box::new(1u8)]].encode_to_vec()?;
+        let err = limits.scope(|| Vec::<Vec<Box<u8>>>::decode(&mut buf.as_slice())).unwrap_err();
+        assert_eq!(
+            "Nesting depth 3 exceeds limit 2; at:([0][0])",
+            err.to_string()
+        );
+
+        // Depth is restored after each element
+        let buf = vec![vec![1u8], vec![2u8]].encode_to_vec()?;
+        limits.scope(|| Vec::<Vec<u8>>::decode(&mut buf.as_slice()))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hostile_length_prefix() -> Result<(), io::Error> {
+        // Claims u32::MAX elements but contains none: fails without allocating the claimed size.
+        let buf = vec![0xff, 0xff, 0xff, 0xff, 1, 2];
+
+        let err = Vec::<u8>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+        assert_eq!(
+            "Unexpected EOF after 2 of 4294967295 bytes; at:([2])",
+            err.to_string()
+        );
+
+        let err = String::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+
+        let err = Vec::<u64>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+
+        let err = Vec::<String>::decode(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+
+        Ok(())
+    }
+}
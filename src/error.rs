@@ -0,0 +1,405 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::io;
+use std::string::FromUtf8Error;
+use std::time::Duration;
+
+use derive_more::Display;
+
+use crate::ByteOrder;
+
+/// The reason a value could not be encoded or decoded.
+///
+/// Codecs return [`io::Error`], so that they compose with the I/O they wrap. Errors raised by
+/// codeq itself carry an `Error` inside the [`io::Error`], with the matching [`io::ErrorKind`],
+/// so that callers can branch on the exact failure with [`Error::from_io()`] instead of matching
+/// the message.
+///
+/// Example:
+/// ```rust
+/// use codeq::{Decode, Error};
+///
+/// let err = bool::decode(&mut [2u8].as_slice()).unwrap_err();
+/// assert_eq!(
+///     Error::from_io(&err),
+///     Some(&Error::InvalidValue {
+///         type_name: "bool",
+///         value: "2".to_string()
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Display, derive_more::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// The checksum stored in the data does not match the checksum computed from the data.
+    #[display(
+        "{algorithm} checksum mismatch: stored {}, computed {}, over {bytes} bytes, while {context}",
+        Hex(stored),
+        Hex(computed)
+    )]
+    ChecksumMismatch {
+        /// The checksum algorithm, see [`CodeqConfig::ALGORITHM`].
+        ///
+        /// [`CodeqConfig::ALGORITHM`]: crate::config::CodeqConfig::ALGORITHM
+        algorithm: &'static str,
+        /// The checksum stored in the data, as encoded.
+        stored: Vec<u8>,
+        /// The checksum computed from the data, encoded the same way as `stored`.
+        computed: Vec<u8>,
+        /// The number of bytes the checksum covers.
+        bytes: u64,
+        /// What was being decoded.
+        context: String,
+    },
+
+    /// An enum-like type, such as `Option`, is tagged with an unknown value.
+    #[display("Invalid {type_name} tag: {tag}")]
+    InvalidTag { type_name: &'static str, tag: u64 },
+
+    /// A decoded value is not a valid value of its type, e.g., a `bool` byte other than 0 or 1.
+    #[display("Invalid {type_name} value: {value}")]
+    InvalidValue {
+        type_name: &'static str,
+        value: String,
+    },
+
+    /// A string is not valid UTF-8.
+    #[display("Invalid UTF-8: {_0}")]
+    InvalidUtf8(#[error(source)] FromUtf8Error),
+
+    /// A NaN other than the canonical one, decoding a [`CanonicalFloat`].
+    ///
+    /// [`CanonicalFloat`]: crate::CanonicalFloat
+    #[display("Non-canonical NaN: {bits:#x}")]
+    NonCanonicalNan { bits: u64 },
+
+    /// A map contains the same key twice.
+    #[display("Duplicate map key")]
+    DuplicateMapKey,
+
+    /// A set contains the same element twice.
+    #[display("Duplicate set element")]
+    DuplicateSetElement,
+
+    /// A varint does not fit in the target integer type.
+    #[display("Varint overflows {type_name}")]
+    VarintOverflow { type_name: &'static str },
+
+    /// A varint has trailing zero groups.
+    #[display("Overlong varint encoding")]
+    OverlongVarint,
+
+    /// Encoding a length that does not fit in the length prefix.
+    #[display("Length {len} does not fit in {prefix} length prefix")]
+    LengthOverflow { len: u64, prefix: &'static str },
+
+    /// A decoded length does not fit in `usize`.
+    #[display("Length {len} does not fit in usize")]
+    LengthTooLarge { len: u64 },
+
+    /// Decoding exceeds one of the current [`DecodeLimits`].
+    ///
+    /// [`DecodeLimits`]: crate::DecodeLimits
+    #[display("{limit} {value} exceeds limit {max}")]
+    LimitExceeded {
+        limit: Limit,
+        value: usize,
+        max: usize,
+    },
+
+    /// The input ends before the value is complete: a primitive, or the content of a string or
+    /// of a sequence of fixed-size elements.
+    #[display("Unexpected EOF after {read} of {expected} bytes")]
+    UnexpectedEof {
+        /// The number of bytes of the value read before the end of input, not a stream offset.
+        read: u64,
+        /// The number of bytes the value takes.
+        expected: u64,
+    },
+
+    /// Encoding a `SystemTime` earlier than `UNIX_EPOCH`.
+    #[display("SystemTime before UNIX_EPOCH: {before:?}")]
+    TimeBeforeEpoch { before: Duration },
+
+    /// A keyed checksum, such as `HmacSha256`, is used without a key in its
+    /// [`ChecksumOptions`](crate::ChecksumOptions).
+    #[display("{algorithm} checksum requires a key")]
+    MissingChecksumKey { algorithm: &'static str },
+
+    /// Encoding or decoding a type in a byte order its codec does not implement, see
+    /// [`Encode::encode_with_order()`].
+    ///
+    /// [`Encode::encode_with_order()`]: crate::Encode::encode_with_order
+    #[display("{type_name} does not support {order:?} byte order")]
+    UnsupportedByteOrder {
+        type_name: &'static str,
+        order: ByteOrder,
+    },
+}
+
+/// The resource limited by a field of [`DecodeLimits`].
+///
+/// [`DecodeLimits`]: crate::DecodeLimits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Limit {
+    /// [`DecodeLimits::max_len`](crate::DecodeLimits::max_len)
+    Len,
+    /// [`DecodeLimits::max_alloc`](crate::DecodeLimits::max_alloc)
+    Alloc,
+    /// [`DecodeLimits::max_total_alloc`](crate::DecodeLimits::max_total_alloc)
+    TotalAlloc,
+    /// [`DecodeLimits::max_depth`](crate::DecodeLimits::max_depth)
+    Depth,
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Limit::Len => write!(f, "Collection length"),
+            Limit::Alloc => write!(f, "Allocation size"),
+            Limit::TotalAlloc => write!(f, "Total allocation size"),
+            Limit::Depth => write!(f, "Nesting depth"),
+        }
+    }
+}
+
+/// Formats bytes as a hex number, e.g., `0x00ff`.
+struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x")?;
+        for b in self.0 {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error {
+    /// The [`io::ErrorKind`] of the [`io::Error`] that carries this error.
+    pub fn kind(&self) -> io::ErrorKind {
+        match self {
+            Error::LengthOverflow { .. }
+            | Error::TimeBeforeEpoch { .. }
+            | Error::MissingChecksumKey { .. } => io::ErrorKind::InvalidInput,
+            Error::UnexpectedEof { .. } => io::ErrorKind::UnexpectedEof,
+            Error::UnsupportedByteOrder { .. } => io::ErrorKind::Unsupported,
+            _ => io::ErrorKind::InvalidData,
+        }
+    }
+
+    /// Returns the `Error` carried by an [`io::Error`], if it is raised by codeq.
+    ///
+    /// Looks through a [`LocatedError`] wrapping the error.
+    pub fn from_io(e: &io::Error) -> Option<&Error> {
+        let inner = e.get_ref()?;
+        match inner.downcast_ref::<LocatedError>() {
+            Some(located) => Self::from_io(&located.source),
+            None => inner.downcast_ref(),
+        }
+    }
+}
+
+/// An error with where in the input it occurred: the byte offset and the path of fields being
+/// decoded, e.g., `Record.data.len`, and the contexts added by the caller.
+///
+/// It is carried inside an [`io::Error`] of the same [`io::ErrorKind`] as the wrapped error, and
+/// is built up as the error propagates: [`OffsetReader::decode()`] records the offset and the
+/// type name, derived `Decode` impls and the built-in collection codecs record the path. All of
+/// them can also be added with [`ErrorContextExt`].
+///
+/// It displays as the wrapped error followed by the location and the contexts, e.g.,
+/// `Duplicate set element; at:(s[1], offset 5); when:(load)`. The wrapped error is kept as is in
+/// [`error()`](Self::error), so that its payload can still be downcast, e.g., with
+/// [`Error::from_io()`]; since its message is already displayed, [`source()`] skips it and
+/// returns its cause.
+///
+/// Example:
+/// ```rust
+/// use codeq::{Encode, LocatedError, OffsetReader};
+///
+/// let buf = (1u32, "ab").encode_to_vec().unwrap();
+///
+/// // Truncated string content
+/// let mut r = OffsetReader::new(&buf[..9]);
+/// let err = r.decode::<(u32, String)>().unwrap_err();
+///
+/// let located = LocatedError::from_io(&err).unwrap();
+/// assert_eq!(located.offset(), Some(9));
+/// assert_eq!(located.path(), "(u32, String).1[1]");
+/// ```
+///
+/// [`OffsetReader::decode()`]: crate::OffsetReader::decode
+/// [`ErrorContextExt`]: crate::error_context_ext::ErrorContextExt
+/// [`source()`]: std::error::Error::source
+#[derive(Debug)]
+pub struct LocatedError {
+    offset: Option<u64>,
+    /// Outermost first.
+    path: Vec<Cow<'static, str>>,
+    /// In the order added, i.e., innermost first.
+    contexts: Vec<String>,
+    source: io::Error,
+}
+
+impl LocatedError {
+    /// Returns the `LocatedError` carried by an [`io::Error`], if any.
+    pub fn from_io(e: &io::Error) -> Option<&LocatedError> {
+        e.get_ref()?.downcast_ref()
+    }
+
+    /// The offset of the first byte not consumed when the error occurred, counted from where the
+    /// [`OffsetReader`](crate::OffsetReader) started.
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// The fields being decoded, outermost first, joined with `.`; empty if unknown.
+    ///
+    /// Element indexes are shown as `[i]`, e.g., `Record.items[3].name`.
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+        for (i, segment) in self.path.iter().enumerate() {
+            if i > 0 && !segment.starts_with('[') {
+                path.push('.');
+            }
+            path.push_str(segment);
+        }
+        path
+    }
+
+    /// The contexts added with [`ErrorContextExt::context()`], innermost first.
+    ///
+    /// [`ErrorContextExt::context()`]: crate::error_context_ext::ErrorContextExt::context
+    pub fn contexts(&self) -> &[String] {
+        &self.contexts
+    }
+
+    /// The error without location and contexts.
+    pub fn error(&self) -> &io::Error {
+        &self.source
+    }
+
+    /// Sets the offset of the error, unless one is already recorded by an inner reader.
+    pub(crate) fn at_offset(e: io::Error, offset: u64) -> io::Error {
+        Self::update(e, |l| {
+            l.offset.get_or_insert(offset);
+        })
+    }
+
+    /// Prepends a segment to the path of the error.
+    pub(crate) fn in_path(e: io::Error, segment: Cow<'static, str>) -> io::Error {
+        Self::update(e, |l| l.path.insert(0, segment))
+    }
+
+    /// Appends a context to the error.
+    pub(crate) fn with_context(e: io::Error, context: String) -> io::Error {
+        Self::update(e, |l| l.contexts.push(context))
+    }
+
+    fn update(e: io::Error, f: impl FnOnce(&mut LocatedError)) -> io::Error {
+        let kind = e.kind();
+
+        let mut located = if e.get_ref().is_some_and(|x| x.is::<LocatedError>()) {
+            *e.into_inner().unwrap().downcast::<LocatedError>().unwrap()
+        } else {
+            LocatedError {
+                offset: None,
+                path: vec![],
+                contexts: vec![],
+                source: e,
+            }
+        };
+
+        f(&mut located);
+        io::Error::new(kind, located)
+    }
+}
+
+impl fmt::Display for LocatedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)?;
+
+        if !self.path.is_empty() || self.offset.is_some() {
+            write!(f, "; at:(")?;
+            if !self.path.is_empty() {
+                write!(f, "{}", self.path())?;
+                if self.offset.is_some() {
+                    write!(f, ", ")?;
+                }
+            }
+            if let Some(offset) = self.offset {
+                write!(f, "offset {}", offset)?;
+            }
+            write!(f, ")")?;
+        }
+
+        for context in &self.contexts {
+            write!(f, "; when:({})", context)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for LocatedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.source()
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        io::Error::new(e.kind(), e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+    use std::io;
+    use std::string::FromUtf8Error;
+
+    use super::Error;
+    use crate::error_context_ext::ErrorContextExt;
+    use crate::Decode;
+    use crate::LocatedError;
+
+    #[test]
+    fn test_error_in_io_error() {
+        let err: io::Error = Error::DuplicateMapKey.into();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!("Duplicate map key", err.to_string());
+        assert_eq!(Some(&Error::DuplicateMapKey), Error::from_io(&err));
+
+        let err: io::Error = Error::LengthOverflow {
+            len: 256,
+            prefix: "u8",
+        }
+        .into();
+        assert_eq!(io::ErrorKind::InvalidInput, err.kind());
+
+        // Not raised by codeq
+        let err = io::Error::new(io::ErrorKind::InvalidData, "foo");
+        assert_eq!(None, Error::from_io(&err));
+        assert_eq!(None, Error::from_io(&io::ErrorKind::UnexpectedEof.into()));
+    }
+
+    #[test]
+    fn test_invalid_utf8_source() {
+        let err = String::decode(&mut b"\x00\x00\x00\x01\xff".as_slice()).unwrap_err();
+        let e = Error::from_io(&err).unwrap();
+        assert!(matches!(e, Error::InvalidUtf8(_)));
+        assert!(e.source().is_some());
+
+        // The cause is reached through the location without repeating the wrapped error
+        let err = err.in_path("name");
+        assert_eq!(
+            "Invalid UTF-8: invalid utf-8 sequence of 1 bytes from index 0; at:(name)",
+            err.to_string()
+        );
+        let located = LocatedError::from_io(&err).unwrap();
+        assert!(located.source().unwrap().is::<FromUtf8Error>());
+    }
+}
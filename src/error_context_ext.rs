@@ -1,8 +1,11 @@
 //! Add context to error messages.
 
+use std::borrow::Cow;
 use std::fmt::Display;
 use std::io;
 
+use crate::LocatedError;
+
 /// Trait for adding context to error messages.
 ///
 /// This trait allows adding additional context to error messages by providing
@@ -27,17 +30,59 @@ use std::io;
 /// let res = res.context(|| "some context");
 /// assert_eq!(res.unwrap_err().to_string(), "some error; when:(some context)");
 /// ```
+///
+/// Contexts stack up, and the original error is kept, with its [`io::ErrorKind`] and payload:
+/// ```rust
+/// # use std::io;
+/// use codeq::error_context_ext::ErrorContextExt;
+/// use codeq::{Decode, Error, LocatedError};
+///
+/// let err = bool::decode(&mut [2u8].as_slice())
+///     .context(|| "read flag")
+///     .context(|| "read header")
+///     .unwrap_err();
+///
+/// assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+/// assert_eq!(
+///     err.to_string(),
+///     "Invalid bool value: 2; when:(read flag); when:(read header)"
+/// );
+/// assert_eq!(
+///     LocatedError::from_io(&err).unwrap().contexts(),
+///     &["read flag", "read header"]
+/// );
+/// assert!(matches!(Error::from_io(&err), Some(Error::InvalidValue { .. })));
+/// ```
 pub trait ErrorContextExt {
     /// Adds context to the error message.
     ///
+    /// The error is wrapped in a [`LocatedError`], which keeps the original error and the
+    /// [`io::ErrorKind`] unchanged.
+    ///
     /// # Arguments
     /// * `ctx` - A closure that returns a displayable context string
     fn context<D: Display>(self, ctx: impl FnOnce() -> D) -> Self;
+
+    /// Records the byte offset at which the error occurred, in a [`LocatedError`].
+    ///
+    /// An offset already recorded, i.e., by an inner reader, is kept.
+    fn at_offset(self, offset: u64) -> Self;
+
+    /// Prepends a field name or type name to the path recorded in a [`LocatedError`].
+    fn in_path(self, segment: impl Into<Cow<'static, str>>) -> Self;
 }
 
 impl ErrorContextExt for io::Error {
     fn context<D: Display>(self, ctx: impl FnOnce() -> D) -> Self {
-        io::Error::new(self.kind(), format!("{}; when:({})", self, ctx()))
+        LocatedError::with_context(self, ctx().to_string())
+    }
+
+    fn at_offset(self, offset: u64) -> Self {
+        LocatedError::at_offset(self, offset)
+    }
+
+    fn in_path(self, segment: impl Into<Cow<'static, str>>) -> Self {
+        LocatedError::in_path(self, segment.into())
     }
 }
 
@@ -45,4 +90,54 @@ impl<T> ErrorContextExt for Result<T, io::Error> {
     fn context<D: Display>(self, ctx: impl FnOnce() -> D) -> Self {
         self.map_err(|e| e.context(ctx))
     }
+
+    fn at_offset(self, offset: u64) -> Self {
+        self.map_err(|e| e.at_offset(offset))
+    }
+
+    fn in_path(self, segment: impl Into<Cow<'static, str>>) -> Self {
+        self.map_err(|e| e.in_path(segment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+    use std::io;
+
+    use super::ErrorContextExt;
+    use crate::Error;
+    use crate::LocatedError;
+
+    #[derive(Debug, derive_more::Display, derive_more::Error)]
+    #[display("custom")]
+    struct Custom;
+
+    #[test]
+    fn test_context_keeps_source() {
+        let err = io::Error::new(io::ErrorKind::NotFound, Custom);
+        let err = err.context(|| "a").context(|| 1);
+
+        assert_eq!(io::ErrorKind::NotFound, err.kind());
+        assert_eq!("custom; when:(a); when:(1)", err.to_string());
+
+        let located = LocatedError::from_io(&err).unwrap();
+        assert_eq!(&["a", "1"], located.contexts());
+        assert!(located.error().get_ref().unwrap().is::<Custom>());
+
+        // The message of the wrapped error is displayed already
+        assert!(located.source().is_none());
+    }
+
+    #[test]
+    fn test_context_with_location() {
+        let err: io::Error = Error::DuplicateSetElement.into();
+        let err = err.in_path("[1]").context(|| "load").in_path("s").at_offset(5);
+
+        assert_eq!(
+            "Duplicate set element; at:(s[1], offset 5); when:(load)",
+            err.to_string()
+        );
+        assert_eq!(Some(&Error::DuplicateSetElement), Error::from_io(&err));
+    }
 }
@@ -0,0 +1,247 @@
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::marker::PhantomData;
+
+use crate::decode_limits;
+use crate::error_context_ext::ErrorContextExt;
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+use crate::Varint;
+
+/// The encoding of the length prefix of a string or a sequence.
+///
+/// Implemented for `u8`, `u16`, `u32`, `u64` and their [`Varint`] forms. `u32` is the prefix
+/// used by the built-in `String` and `Vec<T>` codecs.
+///
+/// Encoding a length that does not fit in the prefix returns an [`io::ErrorKind::InvalidInput`]
+/// error, instead of writing a truncated length.
+pub trait LenPrefix {
+    /// Encodes `len` as the prefix in byte order `order` and returns the number of bytes written.
+    fn encode_len<W: Write>(len: usize, order: ByteOrder, w: W) -> Result<usize, io::Error>;
+
+    /// Decodes a prefix in byte order `order` and returns it as a length.
+    fn decode_len<R: Read>(order: ByteOrder, r: R) -> Result<usize, io::Error>;
+}
+
+macro_rules! impl_len_prefix {
+    ($($t:ty),*) => {
+        $(
+            impl LenPrefix for $t {
+                fn encode_len<W: Write>(
+                    len: usize,
+                    order: ByteOrder,
+                    w: W,
+                ) -> Result<usize, io::Error> {
+                    let v = <$t>::try_from(len).map_err(|_| len_overflow(stringify!($t), len))?;
+                    v.encode_with_order(order, w)
+                }
+
+                fn decode_len<R: Read>(order: ByteOrder, r: R) -> Result<usize, io::Error> {
+                    let v = <$t>::decode_with_order(order, r)?;
+                    usize::try_from(v).map_err(|_| len_too_large(v as u64))
+                }
+            }
+
+            impl LenPrefix for Varint<$t> {
+                /// A varint is a byte sequence, the same in either byte order.
+                fn encode_len<W: Write>(
+                    len: usize,
+                    _order: ByteOrder,
+                    w: W,
+                ) -> Result<usize, io::Error> {
+                    let v = <$t>::try_from(len)
+                        .map_err(|_| len_overflow(concat!("Varint<", stringify!($t), ">"), len))?;
+                    Varint(v).encode(w)
+                }
+
+                fn decode_len<R: Read>(_order: ByteOrder, r: R) -> Result<usize, io::Error> {
+                    let v = Varint::<$t>::decode(r)?.0;
+                    usize::try_from(v).map_err(|_| len_too_large(v as u64))
+                }
+            }
+        )*
+    };
+}
+
+impl_len_prefix!(u8, u16, u32, u64);
+
+fn len_overflow(prefix: &'static str, len: usize) -> io::Error {
+    crate::Error::LengthOverflow {
+        len: len as u64,
+        prefix,
+    }
+    .into()
+}
+
+fn len_too_large(len: u64) -> io::Error {
+    crate::Error::LengthTooLarge { len }.into()
+}
+
+/// A `String` or `Vec<T>` encoded with a length prefix of type `P`.
+///
+/// The content is encoded the same as the built-in codec; only the prefix differs, e.g.,
+/// `LenPrefixed<u32, String>` is byte-identical to `String`.
+///
+/// Example:
+/// ```rust
+/// use codeq::{Decode, Encode, LenPrefixed, Varint};
+///
+/// let buf = LenPrefixed::<u8, String>::new("foo".to_string()).encode_to_vec().unwrap();
+/// assert_eq!(buf, b"\x03foo");
+///
+/// let buf = LenPrefixed::<Varint<u64>, Vec<u8>>::new(vec![1, 2]).encode_to_vec().unwrap();
+/// assert_eq!(buf, vec![2, 1, 2]);
+///
+/// // 256 bytes do not fit in a u8 prefix
+/// let err = LenPrefixed::<u8, Vec<u8>>::new(vec![0; 256]).encode_to_vec().unwrap_err();
+/// assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LenPrefixed<P, T> {
+    data: T,
+    _p: PhantomData<P>,
+}
+
+impl<P, T> LenPrefixed<P, T> {
+    /// Creates a new length-prefixed wrapper around the given data.
+    pub fn new(data: T) -> Self {
+        Self {
+            data,
+            _p: PhantomData,
+        }
+    }
+
+    /// Unwraps and returns the inner data.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<P: LenPrefix> Encode for LenPrefixed<P, String> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, io::Error> {
+        let bytes = self.data.as_bytes();
+        let n = P::encode_len(bytes.len(), order, &mut w)?;
+        w.write_all(bytes)?;
+        Ok(n + bytes.len())
+    }
+}
+
+impl<P: LenPrefix> Decode for LenPrefixed<P, String> {
+    fn decode<R: Read>(r: R) -> Result<Self, io::Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, io::Error> {
+        let len = P::decode_len(order, &mut r).in_path("len")?;
+        let bytes = u8::decode_vec(len, order, r)?;
+        let s = String::from_utf8(bytes).map_err(crate::Error::InvalidUtf8)?;
+        Ok(Self::new(s))
+    }
+}
+
+impl<P: LenPrefix, T: Encode> Encode for LenPrefixed<P, Vec<T>> {
+    fn encode<W: Write>(&self, w: W) -> Result<usize, io::Error> {
+        self.encode_with_order(ByteOrder::BigEndian, w)
+    }
+
+    fn encode_with_order<W: Write>(&self, order: ByteOrder, mut w: W) -> Result<usize, io::Error> {
+        let mut n = P::encode_len(self.data.len(), order, &mut w)?;
+        n += T::encode_slice(&self.data, order, &mut w)?;
+        Ok(n)
+    }
+}
+
+impl<P: LenPrefix, T: Decode> Decode for LenPrefixed<P, Vec<T>> {
+    fn decode<R: Read>(r: R) -> Result<Self, io::Error> {
+        Self::decode_with_order(ByteOrder::BigEndian, r)
+    }
+
+    fn decode_with_order<R: Read>(order: ByteOrder, mut r: R) -> Result<Self, io::Error> {
+        decode_limits::nested(|| {
+            let len = P::decode_len(order, &mut r).in_path("len")?;
+            Ok(Self::new(T::decode_vec(len, order, r)?))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::LenPrefix;
+    use super::LenPrefixed;
+    use crate::ByteOrder;
+    use crate::Decode;
+    use crate::Encode;
+    use crate::Varint;
+
+    fn check<P: LenPrefix>(s: &str, want: &[u8]) -> Result<(), io::Error> {
+        let v = LenPrefixed::<P, String>::new(s.to_string());
+        let buf = v.encode_to_vec()?;
+        assert_eq!(want, buf.as_slice());
+
+        let got = LenPrefixed::<P, String>::decode(&mut buf.as_slice())?;
+        assert_eq!(s, got.into_inner());
+        Ok(())
+    }
+
+    #[test]
+    fn test_len_prefixed_string() -> Result<(), io::Error> {
+        check::<u8>("ab", b"\x02ab")?;
+        check::<u16>("ab", b"\x00\x02ab")?;
+        check::<u32>("ab", b"\x00\x00\x00\x02ab")?;
+        check::<u64>("ab", b"\x00\x00\x00\x00\x00\x00\x00\x02ab")?;
+        check::<Varint<u64>>("ab", b"\x02ab")?;
+
+        // Same as the built-in codec
+        assert_eq!(
+            "ab".to_string().encode_to_vec()?,
+            LenPrefixed::<u32, String>::new("ab".to_string()).encode_to_vec()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_len_prefixed_vec() -> Result<(), io::Error> {
+        let v = LenPrefixed::<u16, Vec<u32>>::new(vec![1, 2]);
+        let buf = v.encode_to_vec()?;
+        assert_eq!(vec![0, 2, 0, 0, 0, 1, 0, 0, 0, 2], buf);
+        assert_eq!(v, LenPrefixed::decode(&mut buf.as_slice())?);
+
+        let v = LenPrefixed::<Varint<u32>, Vec<u8>>::new(vec![7; 200]);
+        let buf = v.encode_to_vec()?;
+        assert_eq!(vec![0xc8, 0x01], buf[..2].to_vec());
+        assert_eq!(202, buf.len());
+        assert_eq!(v, LenPrefixed::decode(&mut buf.as_slice())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_len_prefix_overflow() -> Result<(), io::Error> {
+        let err = LenPrefixed::<u8, String>::new("a".repeat(256)).encode_to_vec().unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidInput, err.kind());
+        assert_eq!(
+            "Length 256 does not fit in u8 length prefix",
+            err.to_string()
+        );
+
+        let err =
+            LenPrefixed::<Varint<u8>, Vec<u8>>::new(vec![0; 256]).encode_to_vec().unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidInput, err.kind());
+
+        let mut buf = Vec::new();
+        let err = u16::encode_len(65536, ByteOrder::BigEndian, &mut buf).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidInput, err.kind());
+        assert!(buf.is_empty(), "nothing is written on overflow");
+
+        Ok(())
+    }
+}
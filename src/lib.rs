@@ -6,8 +6,7 @@
 //!
 //! Choose **this crate** when you need customized and simple binary serialization with checksums,
 //! or when data format evolves over time.
-//! Use **serde** when you need: multiple format support (JSON, YAML, etc.),
-//! or derive macros for automatic implementation.
+//! Use **serde** when you need multiple format support (JSON, YAML, etc.).
 //!
 //! # Core Traits
 //!
@@ -15,14 +14,48 @@
 //! - [`FixedSize`]: For types with known encoded size
 //! - [`Span`]: For types representing a region in a file/buffer
 //!
+//! # Derive
+//!
+//! With the `derive` feature enabled, `#[derive(Encode, Decode, FixedSize)]` generates the
+//! implementations for structs, encoding fields in declaration order. The output is byte-identical
+//! to the hand-written implementation shown in the next example.
+#![cfg_attr(not(feature = "derive"), doc = "```ignore")]
+#![cfg_attr(feature = "derive", doc = "```rust")]
+//! use codeq::{Decode, Encode};
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! struct Record {
+//!     id: u32,
+//!     data: Vec<u8>,
+//! }
+//!
+//! let record = Record { id: 1, data: vec![1, 2, 3] };
+//!
+//! let mut buf = Vec::new();
+//! record.encode(&mut buf).unwrap();
+//! assert_eq!(buf, vec![0, 0, 0, 1, 0, 0, 0, 3, 1, 2, 3]);
+//!
+//! assert_eq!(record, Record::decode(&mut buf.as_slice()).unwrap());
+//! ```
 //! # Utilities
 //!
 //! - [`ChecksumReader`]/[`ChecksumWriter`]: I/O wrappers that calculate checksums
 //! - [`WithChecksum<T>`]: Wraps data with checksum for integrity
+//! - [`CanonicalFloat<T>`]: Float encoding with a single NaN, for deterministic checksums
+//! - [`Varint<T>`]/[`ZigZag<T>`]: Compact variable-length integer encoding
+//! - [`LenPrefixed<P, T>`]: String or `Vec<T>` with a `u8`/`u16`/`u32`/`u64`/varint length prefix
 //! - [`Offset`]: Type-safe byte position in a file/buffer
 //! - [`Size`]: Type-safe byte length
 //! - [`OffsetReader`]/[`OffsetWriter`]: I/O wrappers that track current position
 //! - [`Segment<T>`]: Represents a typed region with offset and size
+//! - [`DecodeLimits`]: Limits on allocation and nesting when decoding untrusted input, per thread
+//! - [`ChecksumKey`]: Secret key of keyed checksum configs, such as HMAC-SHA256
+//! - [`ChecksumOptions`]: Runtime inputs of a checksum: the key of keyed configs and the per-file
+//!   salt of [`config::Seeded`] checksums, which also take a per-type domain tag
+//! - [`config::Dynamic`]: Checksum stored with its algorithm ID, to read data of any algorithm
+//! - [`ByteOrder`]: Big-endian (default) or little-endian, selected per [`config::CodeqConfig`]
+//! - [`Error`]: Typed decode/encode failures, carried inside [`std::io::Error`]
+//! - [`LocatedError`]: Byte offset and field path of a decode error, see [`OffsetReader::decode()`]
 //!
 //! # Examples
 //!
@@ -82,21 +115,34 @@
 //! [`Size`]: crate::Size
 //! [`Segment<T>`]: crate::Segment
 //! [`WithChecksum<T>`]: crate::WithChecksum
+//! [`CanonicalFloat<T>`]: crate::CanonicalFloat
+//! [`Varint<T>`]: crate::Varint
+//! [`ZigZag<T>`]: crate::ZigZag
 //! [`ChecksumReader`]: crate::ChecksumReader
 //! [`ChecksumWriter`]: crate::ChecksumWriter
+//! [`ChecksumKey`]: crate::ChecksumKey
+//! [`ChecksumOptions`]: crate::ChecksumOptions
 //! [`OffsetReader`]: crate::OffsetReader
 //! [`OffsetWriter`]: crate::OffsetWriter
 
 extern crate core;
 
+mod byte_order;
+mod canonical_float;
+mod checksum_key;
+mod checksum_options;
 mod checksum_reader;
 mod checksum_writer;
 mod codec;
+mod decode_limits;
+mod error;
 mod fixed_size;
+mod len_prefixed;
 mod offset_reader;
 mod offset_writer;
 mod segment;
 mod span;
+mod varint;
 mod with_checksum;
 
 pub mod config;
@@ -104,12 +150,28 @@ pub mod error_context_ext;
 pub(crate) mod sealed;
 pub mod testing;
 
+pub use byte_order::ByteOrder;
+pub use canonical_float::CanonicalFloat;
+pub use checksum_key::ChecksumKey;
+pub use checksum_options::ChecksumOptions;
 pub use checksum_reader::ChecksumReader;
 pub use checksum_writer::ChecksumWriter;
 pub use codec::Codec;
 pub use codec::Decode;
 pub use codec::Encode;
+#[cfg(feature = "derive")]
+pub use codeq_derive::Decode;
+#[cfg(feature = "derive")]
+pub use codeq_derive::Encode;
+#[cfg(feature = "derive")]
+pub use codeq_derive::FixedSize;
+pub use decode_limits::DecodeLimits;
+pub use error::Error;
+pub use error::Limit;
+pub use error::LocatedError;
 pub use fixed_size::FixedSize;
+pub use len_prefixed::LenPrefix;
+pub use len_prefixed::LenPrefixed;
 pub use offset_reader::OffsetReader;
 pub use offset_writer::OffsetWriter;
 pub use segment::Segment;
@@ -118,4 +180,6 @@ pub use span::Size;
 pub use span::Span;
 // Backward compatibility
 pub use span::Span as OffsetSize;
+pub use varint::Varint;
+pub use varint::ZigZag;
 pub use with_checksum::WithChecksum;
@@ -1,5 +1,9 @@
+use std::any::type_name;
 use std::io;
 
+use crate::error_context_ext::ErrorContextExt;
+use crate::Decode;
+
 /// A reader that tracks the number of bytes read.
 ///
 /// This reader wraps any type implementing [`io::Read`] and transparently tracks
@@ -35,6 +39,52 @@ impl<R: io::Read> OffsetReader<R> {
     pub fn offset(&self) -> usize {
         self.offset
     }
+
+    /// Decodes a `T` from the reader, recording in a decode error where it occurred.
+    ///
+    /// The error carries a [`LocatedError`](crate::LocatedError) with the offset of this reader
+    /// at the failure, and a path starting with the name of `T`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use codeq::{Encode, OffsetReader};
+    ///
+    /// let buf = (1u64, 2u8).encode_to_vec().unwrap();
+    ///
+    /// let mut r = OffsetReader::new(buf.as_slice());
+    /// let err = r.decode::<(u64, bool)>().unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "Invalid bool value: 2; at:((u64, bool).1, offset 9)"
+    /// );
+    /// ```
+    pub fn decode<T: Decode>(&mut self) -> io::Result<T> {
+        T::decode(&mut *self)
+            .map_err(|e| e.in_path(short_type_name::<T>()).at_offset(self.offset as u64))
+    }
+}
+
+/// The name of `T` without module paths, e.g., `Vec<Record>` instead of
+/// `alloc::vec::Vec<my_crate::Record>`.
+fn short_type_name<T: ?Sized>() -> String {
+    let full = type_name::<T>();
+
+    let mut short = String::with_capacity(full.len());
+    let mut ident_start = 0;
+    for (i, c) in full.char_indices() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            continue;
+        }
+        short.push_str(last_path_segment(&full[ident_start..i]));
+        short.push(c);
+        ident_start = i + c.len_utf8();
+    }
+    short.push_str(last_path_segment(&full[ident_start..]));
+    short
+}
+
+fn last_path_segment(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
 }
 
 impl<R: io::Read> io::Read for OffsetReader<R> {
@@ -50,7 +100,11 @@ mod tests {
     use std::io;
     use std::io::Read;
 
+    use super::short_type_name;
     use crate::offset_reader::OffsetReader;
+    use crate::Encode;
+    use crate::Error;
+    use crate::LocatedError;
 
     #[test]
     fn test_offset_reader() -> Result<(), io::Error> {
@@ -66,4 +120,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_offset_reader_decode() -> Result<(), io::Error> {
+        let mut buf = Vec::new();
+        1u32.encode(&mut buf)?;
+        Some(2u8).encode(&mut buf)?;
+        buf.push(5); // invalid Option tag
+
+        let mut reader = OffsetReader::new(buf.as_slice());
+        assert_eq!(1, reader.decode::<u32>()?);
+        assert_eq!(Some(2), reader.decode::<Option<u8>>()?);
+
+        let err = reader.decode::<Option<u8>>().unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!(
+            "Invalid Option tag: 5; at:(Option<u8>, offset 7)",
+            err.to_string()
+        );
+
+        let located = LocatedError::from_io(&err).unwrap();
+        assert_eq!(Some(7), located.offset());
+        assert_eq!("Option<u8>", located.path());
+
+        // The typed error is still reachable
+        assert_eq!(
+            Some(&Error::InvalidTag {
+                type_name: "Option",
+                tag: 5
+            }),
+            Error::from_io(&err)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!("u64", short_type_name::<u64>());
+        assert_eq!("Vec<String>", short_type_name::<Vec<String>>());
+        assert_eq!(
+            "(u8, BTreeMap<String, [u32; 2]>)",
+            short_type_name::<(u8, std::collections::BTreeMap<String, [u32; 2]>)>()
+        );
+    }
 }
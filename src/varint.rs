@@ -0,0 +1,300 @@
+use std::io;
+use std::io::Read;
+use std::io::Write;
+
+use crate::ByteOrder;
+use crate::Decode;
+use crate::Encode;
+
+/// An unsigned integer encoded as LEB128, in 1 to `ceil(BITS / 7)` bytes.
+///
+/// Each byte carries 7 bits of the value, least significant group first; the high bit is set on
+/// every byte but the last. Small values take fewer bytes than the fixed-width big-endian
+/// encoding, e.g., a `Varint<u64>` below 128 takes 1 byte instead of 8.
+///
+/// Decoding is strict so that corrupted bytes are reported instead of silently misread:
+/// an overlong encoding (a trailing `0x00` group), or one whose value does not fit in `T`,
+/// returns an [`io::ErrorKind::InvalidData`] error.
+///
+/// Example:
+/// ```rust
+/// use codeq::{Decode, Encode, Varint};
+///
+/// let buf = Varint(300u64).encode_to_vec().unwrap();
+/// assert_eq!(buf, vec![0xac, 0x02]);
+///
+/// let v = Varint::<u64>::decode(&mut buf.as_slice()).unwrap();
+/// assert_eq!(v, Varint(300));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Varint<T>(pub T);
+
+/// A signed integer zigzag-mapped to unsigned and then encoded as a [`Varint`].
+///
+/// Zigzag maps `0, -1, 1, -2, 2, ...` to `0, 1, 2, 3, 4, ...`, so that values close to zero
+/// take few bytes regardless of sign.
+///
+/// Example:
+/// ```rust
+/// use codeq::{Encode, ZigZag};
+///
+/// assert_eq!(ZigZag(-1i64).encode_to_vec().unwrap(), vec![0x01]);
+/// assert_eq!(ZigZag(1i64).encode_to_vec().unwrap(), vec![0x02]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZigZag<T>(pub T);
+
+impl<T> Varint<T> {
+    /// Unwraps and returns the inner integer.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> ZigZag<T> {
+    /// Unwraps and returns the inner integer.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+fn encode_leb128<W: Write>(mut v: u128, mut w: W) -> Result<usize, io::Error> {
+    let mut buf = [0u8; 19];
+    let mut n = 0;
+
+    loop {
+        let group = (v & 0x7f) as u8;
+        v >>= 7;
+
+        if v == 0 {
+            buf[n] = group;
+            n += 1;
+            break;
+        }
+        buf[n] = group | 0x80;
+        n += 1;
+    }
+
+    w.write_all(&buf[..n])?;
+    Ok(n)
+}
+
+/// Decodes a LEB128 value that must fit in `bits` bits.
+fn decode_leb128<R: Read>(mut r: R, bits: u32, type_name: &'static str) -> Result<u128, io::Error> {
+    let mut v = 0u128;
+    let mut shift = 0u32;
+
+    loop {
+        let b = u8::decode(&mut r)?;
+        let group = (b & 0x7f) as u128;
+
+        if shift >= bits || (shift + 7 > bits && group >> (bits - shift) != 0) {
+            return Err(crate::Error::VarintOverflow { type_name }.into());
+        }
+
+        v |= group << shift;
+
+        if b & 0x80 == 0 {
+            if b == 0 && shift > 0 {
+                return Err(crate::Error::OverlongVarint.into());
+            }
+            return Ok(v);
+        }
+        shift += 7;
+    }
+}
+
+macro_rules! impl_varint {
+    ($t:ty) => {
+        impl Encode for Varint<$t> {
+            fn encode<W: Write>(&self, w: W) -> Result<usize, io::Error> {
+                encode_leb128(self.0 as u128, w)
+            }
+
+            /// LEB128 is a byte sequence, the same in either byte order.
+            fn encode_with_order<W: Write>(
+                &self,
+                _order: ByteOrder,
+                w: W,
+            ) -> Result<usize, io::Error> {
+                self.encode(w)
+            }
+        }
+
+        impl Decode for Varint<$t> {
+            fn decode<R: Read>(r: R) -> Result<Self, io::Error> {
+                let v = decode_leb128(r, <$t>::BITS, stringify!($t))?;
+                Ok(Self(v as $t))
+            }
+
+            fn decode_with_order<R: Read>(_order: ByteOrder, r: R) -> Result<Self, io::Error> {
+                Self::decode(r)
+            }
+        }
+    };
+}
+
+impl_varint!(u8);
+impl_varint!(u16);
+impl_varint!(u32);
+impl_varint!(u64);
+impl_varint!(u128);
+
+macro_rules! impl_zigzag {
+    ($i:ty, $u:ty) => {
+        impl Encode for ZigZag<$i> {
+            fn encode<W: Write>(&self, w: W) -> Result<usize, io::Error> {
+                let n = self.0;
+                let u = ((n << 1) ^ (n >> (<$i>::BITS - 1))) as $u;
+                Varint(u).encode(w)
+            }
+
+            /// LEB128 is a byte sequence, the same in either byte order.
+            fn encode_with_order<W: Write>(
+                &self,
+                _order: ByteOrder,
+                w: W,
+            ) -> Result<usize, io::Error> {
+                self.encode(w)
+            }
+        }
+
+        impl Decode for ZigZag<$i> {
+            fn decode<R: Read>(r: R) -> Result<Self, io::Error> {
+                let u = Varint::<$u>::decode(r)?.0;
+                let n = ((u >> 1) as $i) ^ -((u & 1) as $i);
+                Ok(Self(n))
+            }
+
+            fn decode_with_order<R: Read>(_order: ByteOrder, r: R) -> Result<Self, io::Error> {
+                Self::decode(r)
+            }
+        }
+    };
+}
+
+impl_zigzag!(i8, u8);
+impl_zigzag!(i16, u16);
+impl_zigzag!(i32, u32);
+impl_zigzag!(i64, u64);
+impl_zigzag!(i128, u128);
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::Varint;
+    use super::ZigZag;
+    use crate::Decode;
+    use crate::Encode;
+
+    #[test]
+    fn test_varint_codec() -> Result<(), io::Error> {
+        let cases: &[(u64, &[u8])] = &[
+            (0, &[0x00]),
+            (1, &[0x01]),
+            (127, &[0x7f]),
+            (128, &[0x80, 0x01]),
+            (300, &[0xac, 0x02]),
+            (16384, &[0x80, 0x80, 0x01]),
+            (u64::MAX, &[
+                0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01,
+            ]),
+        ];
+
+        for (v, want) in cases {
+            let mut buf = Vec::new();
+            let n = Varint(*v).encode(&mut buf)?;
+            assert_eq!(n, buf.len());
+            assert_eq!(*want, buf.as_slice(), "encode {}", v);
+
+            let got = Varint::<u64>::decode(&mut buf.as_slice())?;
+            assert_eq!(*v, got.0);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_varint_max_values() -> Result<(), io::Error> {
+        let buf = Varint(u8::MAX).encode_to_vec()?;
+        assert_eq!(vec![0xff, 0x01], buf);
+        assert_eq!(u8::MAX, Varint::<u8>::decode(&mut buf.as_slice())?.0);
+
+        let buf = Varint(u32::MAX).encode_to_vec()?;
+        assert_eq!(vec![0xff, 0xff, 0xff, 0xff, 0x0f], buf);
+        assert_eq!(u32::MAX, Varint::<u32>::decode(&mut buf.as_slice())?.0);
+
+        let buf = Varint(u128::MAX).encode_to_vec()?;
+        assert_eq!(19, buf.len());
+        assert_eq!(u128::MAX, Varint::<u128>::decode(&mut buf.as_slice())?.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_varint_rejects_overlong() {
+        for buf in [&[0x80u8, 0x00][..], &[0x81, 0x80, 0x00]] {
+            let err = Varint::<u64>::decode(&mut &buf[..]).unwrap_err();
+            assert_eq!(io::ErrorKind::InvalidData, err.kind());
+            assert_eq!("Overlong varint encoding", err.to_string());
+        }
+    }
+
+    #[test]
+    fn test_varint_rejects_overflow() {
+        // u32::MAX + 1
+        let buf = [0x80u8, 0x80, 0x80, 0x80, 0x10];
+        let err = Varint::<u32>::decode(&mut &buf[..]).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!("Varint overflows u32", err.to_string());
+
+        // Too many bytes
+        let buf = [0xffu8; 11];
+        let err = Varint::<u64>::decode(&mut &buf[..]).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+        // 256 does not fit in u8
+        let buf = [0x80u8, 0x02];
+        let err = Varint::<u8>::decode(&mut &buf[..]).unwrap_err();
+        assert_eq!("Varint overflows u8", err.to_string());
+    }
+
+    #[test]
+    fn test_varint_truncated() {
+        let err = Varint::<u64>::decode(&mut &[0x80u8][..]).unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+    }
+
+    #[test]
+    fn test_zigzag_codec() -> Result<(), io::Error> {
+        let cases: &[(i64, &[u8])] = &[
+            (0, &[0x00]),
+            (-1, &[0x01]),
+            (1, &[0x02]),
+            (-2, &[0x03]),
+            (63, &[0x7e]),
+            (-64, &[0x7f]),
+            (64, &[0x80, 0x01]),
+        ];
+
+        for (v, want) in cases {
+            let buf = ZigZag(*v).encode_to_vec()?;
+            assert_eq!(*want, buf.as_slice(), "encode {}", v);
+            assert_eq!(*v, ZigZag::<i64>::decode(&mut buf.as_slice())?.0);
+        }
+
+        for v in [i64::MIN, i64::MAX] {
+            let buf = ZigZag(v).encode_to_vec()?;
+            assert_eq!(10, buf.len());
+            assert_eq!(v, ZigZag::<i64>::decode(&mut buf.as_slice())?.0);
+        }
+
+        for v in [i8::MIN, -1, 0, 1, i8::MAX] {
+            let buf = ZigZag(v).encode_to_vec()?;
+            assert_eq!(v, ZigZag::<i8>::decode(&mut buf.as_slice())?.0);
+        }
+
+        Ok(())
+    }
+}
@@ -7,6 +7,10 @@ use crate::codec::Decode;
 use crate::codec::Encode;
 use crate::config::CodeqConfig;
 use crate::fixed_size::FixedSize;
+use crate::ByteOrder;
+use crate::ChecksumOptions;
+use crate::ChecksumReader;
+use crate::ChecksumWriter;
 
 /// A wrapper that appends a checksum to the encoded data.
 ///
@@ -39,6 +43,9 @@ use crate::fixed_size::FixedSize;
 /// ```ignore
 /// let wc = Crc32fast::wrap(5);
 /// ```
+/// 
+/// [`Encode`] and [`Decode`] use the default [`ChecksumOptions`]; a keyed config needs
+/// [`WithChecksum::encode_with_options`] and [`WithChecksum::decode_with_options`] instead.
 #[derive(Debug)]
 #[derive(Clone)]
 #[derive(PartialEq, Eq)]
@@ -72,43 +79,74 @@ where
     T: FixedSize,
 {
     fn encoded_size() -> usize {
-        T::encoded_size() + 8
+        T::encoded_size() + C::checksum_size()
     }
 }
 
-impl<C, T> Encode for WithChecksum<C, T>
+impl<C, T> WithChecksum<C, T>
 where
     C: CodeqConfig,
     T: Encode,
 {
-    fn encode<W: Write>(&self, mut w: W) -> Result<usize, Error> {
+    /// Encodes the data and its checksum, computed with `options`.
+    pub fn encode_with_options<W: Write>(
+        &self,
+        options: &ChecksumOptions,
+        mut w: W,
+    ) -> Result<usize, Error> {
         let mut n = 0;
-        let mut cw = C::new_writer(&mut w);
+        let mut cw = ChecksumWriter::<C, _>::with_options(&mut w, options)?;
 
-        n += self.data.encode(&mut cw)?;
+        n += self.data.encode_with_order(C::BYTE_ORDER, &mut cw)?;
         n += cw.write_checksum()?;
 
         Ok(n)
     }
 }
 
-impl<C, T> Decode for WithChecksum<C, T>
+impl<C, T> WithChecksum<C, T>
 where
     C: CodeqConfig,
     T: Decode,
 {
-    fn decode<R: Read>(r: R) -> Result<Self, Error> {
-        let mut cr = C::new_reader(r);
+    /// Decodes the data and verifies its checksum, computed with `options`.
+    pub fn decode_with_options<R: Read>(options: &ChecksumOptions, r: R) -> Result<Self, Error> {
+        let mut cr = ChecksumReader::<C, _>::with_options(r, options)?;
 
-        let data = T::decode(&mut cr)?;
+        let data = T::decode_with_order(C::BYTE_ORDER, &mut cr)?;
         cr.verify_checksum(|| "WithChecksum::decode()")?;
 
-        let meta = Self {
-            data,
-            _p: Default::default(),
-        };
+        Ok(Self::new(data))
+    }
+}
+
+impl<C, T> Encode for WithChecksum<C, T>
+where
+    C: CodeqConfig,
+    T: Encode,
+{
+    fn encode<W: Write>(&self, w: W) -> Result<usize, Error> {
+        self.encode_with_options(&ChecksumOptions::default(), w)
+    }
+
+    /// Encodes in the byte order of `C`, whatever `order` is.
+    fn encode_with_order<W: Write>(&self, _order: ByteOrder, w: W) -> Result<usize, Error> {
+        self.encode(w)
+    }
+}
+
+impl<C, T> Decode for WithChecksum<C, T>
+where
+    C: CodeqConfig,
+    T: Decode,
+{
+    fn decode<R: Read>(r: R) -> Result<Self, Error> {
+        Self::decode_with_options(&ChecksumOptions::default(), r)
+    }
 
-        Ok(meta)
+    /// Decodes in the byte order of `C`, whatever `order` is.
+    fn decode_with_order<R: Read>(_order: ByteOrder, r: R) -> Result<Self, Error> {
+        Self::decode(r)
     }
 }
 
@@ -118,7 +156,15 @@ mod tests_crc32fast {
     use crate::codec::Encode;
     use crate::config::CodeqConfig;
     use crate::config::Crc32fast;
+    use crate::config::Crc32fastU32;
+    use crate::config::LittleEndian;
+    use crate::config::Seeded;
     use crate::testing::test_codec;
+    use crate::ByteOrder;
+    use crate::ChecksumOptions;
+    use crate::Decode;
+    use crate::FixedSize;
+    use crate::WithChecksum;
 
     #[test]
     fn test_with_checksum_codec() -> anyhow::Result<()> {
@@ -139,4 +185,347 @@ mod tests_crc32fast {
 
         Ok(())
     }
+
+    #[test]
+    fn test_with_checksum_little_endian() -> anyhow::Result<()> {
+        let wc = LittleEndian::<Crc32fast>::wrap((5u64, "ab".to_string()));
+        let mut b = Vec::new();
+        let n = wc.encode(&mut b)?;
+        assert_eq!(n, b.len());
+
+        assert_eq!(
+            vec![
+                5, 0, 0, 0, 0, 0, 0, 0, // data.0
+                2, 0, 0, 0, b'a', b'b', // data.1
+                51, 236, 138, 199, 0, 0, 0, 0, // checksum
+            ],
+            b
+        );
+
+        test_codec(b.as_slice(), &wc)?;
+
+        // The config, not the caller, decides the byte order
+        let mut b2 = Vec::new();
+        wc.encode_with_order(ByteOrder::BigEndian, &mut b2)?;
+        assert_eq!(b, b2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_checksum_u32() -> anyhow::Result<()> {
+        let wc = Crc32fastU32::wrap(5u64);
+        assert_eq!(12, WithChecksum::<Crc32fastU32, u64>::encoded_size());
+
+        let b = vec![
+            0, 0, 0, 0, 0, 0, 0, 5, // data
+            21, 72, 43, 230, // checksum
+        ];
+
+        test_codec(b.as_slice(), &wc)?;
+
+        // The 4-byte and the 8-byte layouts are not interchangeable
+        let b8 = Crc32fast::wrap(5u64).encode_to_vec()?;
+        assert!(WithChecksum::<Crc32fastU32, u64>::decode(b8.as_slice()).is_err());
+        assert!(WithChecksum::<Crc32fast, u64>::decode(b.as_slice()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_checksum_seeded() -> anyhow::Result<()> {
+        type C = Seeded<Crc32fast, 3>;
+
+        let salt = |salt| ChecksumOptions {
+            salt,
+            ..ChecksumOptions::default()
+        };
+
+        let wc = C::wrap(5u64);
+        let mut b = Vec::new();
+        wc.encode_with_options(&salt(7), &mut b)?;
+
+        let seeded = [
+            &3u64.to_be_bytes()[..], // domain
+            &7u64.to_be_bytes()[..], // salt
+            &b[..8],                 // data
+        ]
+        .concat();
+        assert_eq!(&(crc32fast::hash(&seeded) as u64).to_be_bytes(), &b[8..]);
+
+        let got = WithChecksum::<C, u64>::decode_with_options(&salt(7), b.as_slice())?;
+        assert_eq!(wc, got);
+
+        // Another domain, another salt or the unseeded config
+        let res =
+            WithChecksum::<Seeded<Crc32fast, 4>, u64>::decode_with_options(&salt(7), b.as_slice());
+        assert!(res.is_err());
+
+        let res = WithChecksum::<C, u64>::decode_with_options(&salt(8), b.as_slice());
+        assert!(res.is_err());
+
+        let res = WithChecksum::<Crc32fast, u64>::decode_with_options(&salt(7), b.as_slice());
+        assert!(res.is_err());
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "crc32c")]
+#[cfg(test)]
+mod tests_crc32c {
+    use crate::codec::Encode;
+    use crate::config::CodeqConfig;
+    use crate::config::Crc32c;
+    use crate::config::Crc32cU32;
+    use crate::testing::test_codec;
+
+    #[test]
+    fn test_with_checksum_codec() -> anyhow::Result<()> {
+        let wc = Crc32c::wrap(5u64);
+        let mut b = Vec::new();
+        let n = wc.encode(&mut b)?;
+        assert_eq!(n, b.len());
+
+        assert_eq!(
+            vec![
+                0, 0, 0, 0, 0, 0, 0, 5, // data
+                0, 0, 0, 0, 185, 217, 166, 150, // checksum
+            ],
+            b
+        );
+
+        test_codec(b.as_slice(), &wc)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_checksum_u32() -> anyhow::Result<()> {
+        let wc = Crc32cU32::wrap(5u64);
+
+        let b = vec![
+            0, 0, 0, 0, 0, 0, 0, 5, // data
+            185, 217, 166, 150, // checksum
+        ];
+
+        test_codec(b.as_slice(), &wc)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xxh3")]
+#[cfg(test)]
+mod tests_xxh3 {
+    use crate::codec::Encode;
+    use crate::config::CodeqConfig;
+    use crate::config::Xxh3;
+    use crate::testing::test_codec;
+
+    #[test]
+    fn test_with_checksum_codec() -> anyhow::Result<()> {
+        let wc = Xxh3::wrap(5u64);
+        let mut b = Vec::new();
+        let n = wc.encode(&mut b)?;
+        assert_eq!(n, b.len());
+
+        assert_eq!(
+            vec![
+                0, 0, 0, 0, 0, 0, 0, 5, // data
+                194, 143, 31, 94, 115, 112, 5, 6, // checksum
+            ],
+            b
+        );
+
+        test_codec(b.as_slice(), &wc)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xxh64")]
+#[cfg(test)]
+mod tests_xxh64 {
+    use crate::codec::Encode;
+    use crate::config::CodeqConfig;
+    use crate::config::Xxh64;
+    use crate::testing::test_codec;
+
+    #[test]
+    fn test_with_checksum_codec() -> anyhow::Result<()> {
+        let wc = Xxh64::wrap(5u64);
+        let mut b = Vec::new();
+        let n = wc.encode(&mut b)?;
+        assert_eq!(n, b.len());
+
+        assert_eq!(
+            vec![
+                0, 0, 0, 0, 0, 0, 0, 5, // data
+                219, 50, 182, 224, 79, 83, 179, 124, // checksum
+            ],
+            b
+        );
+
+        test_codec(b.as_slice(), &wc)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blake3")]
+#[cfg(test)]
+mod tests_blake3 {
+    use std::io;
+
+    use crate::codec::Encode;
+    use crate::config::Blake3;
+    use crate::config::Blake3Keyed;
+    use crate::config::CodeqConfig;
+    use crate::testing::test_codec;
+    use crate::ChecksumKey;
+    use crate::ChecksumOptions;
+    use crate::Decode;
+    use crate::Error;
+    use crate::FixedSize;
+    use crate::WithChecksum;
+
+    #[test]
+    fn test_with_checksum_codec() -> anyhow::Result<()> {
+        let wc = Blake3::wrap(5u64);
+        let b = wc.encode_to_vec()?;
+
+        assert_eq!(40, WithChecksum::<Blake3, u64>::encoded_size());
+        assert_eq!(&[0, 0, 0, 0, 0, 0, 0, 5], &b[..8]);
+        assert_eq!(blake3::hash(&b[..8]).as_bytes(), &b[8..]);
+
+        test_codec(b.as_slice(), &wc)?;
+
+        Ok(())
+    }
+
+    fn options(key: [u8; 32]) -> ChecksumOptions {
+        ChecksumOptions {
+            key: Some(ChecksumKey::new(key)),
+            ..ChecksumOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_with_checksum_keyed() -> anyhow::Result<()> {
+        let wc = Blake3Keyed::wrap(5u64);
+        let mut b = Vec::new();
+        let n = wc.encode_with_options(&options([7; 32]), &mut b)?;
+        assert_eq!(n, b.len());
+
+        assert_eq!(blake3::keyed_hash(&[7; 32], &b[..8]).as_bytes(), &b[8..]);
+
+        let got =
+            WithChecksum::<Blake3Keyed, u64>::decode_with_options(&options([7; 32]), b.as_slice())?;
+        assert_eq!(wc, got);
+
+        let err =
+            WithChecksum::<Blake3Keyed, u64>::decode_with_options(&options([8; 32]), b.as_slice())
+                .unwrap_err();
+        assert!(err.to_string().starts_with("blake3-keyed checksum mismatch: "));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_checksum_keyed_without_key() -> anyhow::Result<()> {
+        let err = Blake3Keyed::wrap(5u64).encode_to_vec().unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidInput, err.kind());
+        assert_eq!(
+            Some(&Error::MissingChecksumKey {
+                algorithm: "blake3-keyed"
+            }),
+            Error::from_io(&err)
+        );
+
+        let b = vec![0; 40];
+        let err = WithChecksum::<Blake3Keyed, u64>::decode(b.as_slice()).unwrap_err();
+        assert_eq!("blake3-keyed checksum requires a key", err.to_string());
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sha2")]
+#[cfg(test)]
+mod tests_sha2 {
+    use sha2::Digest;
+
+    use crate::codec::Encode;
+    use crate::config::CodeqConfig;
+    use crate::config::Sha256;
+    use crate::testing::test_codec;
+
+    #[test]
+    fn test_with_checksum_codec() -> anyhow::Result<()> {
+        let wc = Sha256::wrap(5u64);
+        let b = wc.encode_to_vec()?;
+
+        assert_eq!(&[0, 0, 0, 0, 0, 0, 0, 5], &b[..8]);
+        assert_eq!(sha2::Sha256::digest(&b[..8]).as_slice(), &b[8..]);
+
+        test_codec(b.as_slice(), &wc)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "hmac")]
+#[cfg(test)]
+mod tests_hmac {
+    use hmac::Mac;
+
+    use crate::config::CodeqConfig;
+    use crate::config::HmacSha256;
+    use crate::ChecksumKey;
+    use crate::ChecksumOptions;
+    use crate::Error;
+    use crate::WithChecksum;
+
+    #[test]
+    fn test_with_checksum_codec() -> anyhow::Result<()> {
+        let options = ChecksumOptions {
+            key: Some(ChecksumKey::new([7; 32])),
+            ..ChecksumOptions::default()
+        };
+
+        let wc = HmacSha256::wrap(5u64);
+        let mut b = Vec::new();
+        wc.encode_with_options(&options, &mut b)?;
+
+        let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(&[7; 32])?;
+        mac.update(&b[..8]);
+        assert_eq!(mac.finalize().into_bytes().as_slice(), &b[8..]);
+
+        let got = WithChecksum::<HmacSha256, u64>::decode_with_options(&options, b.as_slice())?;
+        assert_eq!(wc, got);
+
+        // A different key computes a different tag
+        let options = ChecksumOptions {
+            key: Some(ChecksumKey::new([8; 32])),
+            ..ChecksumOptions::default()
+        };
+        let err = WithChecksum::<HmacSha256, u64>::decode_with_options(&options, b.as_slice())
+            .unwrap_err();
+
+        let Some(Error::ChecksumMismatch {
+            algorithm,
+            stored,
+            computed,
+            ..
+        }) = Error::from_io(&err)
+        else {
+            panic!("unexpected error: {}", err);
+        };
+        assert_eq!("hmac-sha256", *algorithm);
+        assert_eq!(&b[8..], stored.as_slice());
+        assert_ne!(stored, computed);
+
+        Ok(())
+    }
 }